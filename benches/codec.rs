@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Criterion benchmarks comparing the allocating URL codec entry points with
+//! their borrowing (`*_cow`) and streaming ([`Encoded`]) counterparts.
+
+use std::{fmt::Write, hint::black_box};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use yew_nav_link::utils::{
+    Encoded, QueryParams, percent_decode, percent_decode_cow, urlencoding_decode,
+    urlencoding_decode_cow, urlencoding_encode
+};
+
+const PLAIN_QUERY_VALUE: &str = "rust-wasm-navigation";
+const ENCODED_QUERY_VALUE: &str = "rust+%26+wasm%20%E2%9C%93";
+const PLAIN_PATH: &str = "/docs/api/v1/users/42";
+const ENCODED_PATH: &str = "/docs/api/hello%20world/c++";
+const PLAIN_QUERY: &str = "page=2&limit=50&sort=name&tag=rust&tag=web&tag=yew&tag=ssr";
+const ENCODED_QUERY: &str = "q=rust+%26+wasm&sort=na%6De&tag=r%C3%BCst&tag=web+ui&tag=yew";
+
+fn bench_decode(c: &mut Criterion) {
+    c.bench_function("urlencoding_decode_plain", |b| {
+        b.iter(|| urlencoding_decode(black_box(PLAIN_QUERY_VALUE)));
+    });
+
+    c.bench_function("urlencoding_decode_cow_plain", |b| {
+        b.iter(|| urlencoding_decode_cow(black_box(PLAIN_QUERY_VALUE)));
+    });
+
+    c.bench_function("urlencoding_decode_encoded", |b| {
+        b.iter(|| urlencoding_decode(black_box(ENCODED_QUERY_VALUE)));
+    });
+
+    c.bench_function("urlencoding_decode_cow_encoded", |b| {
+        b.iter(|| urlencoding_decode_cow(black_box(ENCODED_QUERY_VALUE)));
+    });
+
+    c.bench_function("percent_decode_plain_path", |b| {
+        b.iter(|| percent_decode(black_box(PLAIN_PATH)));
+    });
+
+    c.bench_function("percent_decode_cow_plain_path", |b| {
+        b.iter(|| percent_decode_cow(black_box(PLAIN_PATH)));
+    });
+
+    c.bench_function("percent_decode_cow_encoded_path", |b| {
+        b.iter(|| percent_decode_cow(black_box(ENCODED_PATH)));
+    });
+}
+
+fn bench_encode(c: &mut Criterion) {
+    c.bench_function("urlencoding_encode_into_buffer", |b| {
+        let mut buffer = String::with_capacity(128);
+        b.iter(|| {
+            buffer.clear();
+            buffer.push_str("/search?q=");
+            buffer.push_str(&urlencoding_encode(black_box("rust & wasm ✓")));
+            black_box(&buffer);
+        });
+    });
+
+    c.bench_function("encoded_display_into_buffer", |b| {
+        let mut buffer = String::with_capacity(128);
+        b.iter(|| {
+            buffer.clear();
+            let _ = write!(
+                buffer,
+                "/search?q={}",
+                Encoded::form(black_box("rust & wasm ✓"))
+            );
+            black_box(&buffer);
+        });
+    });
+}
+
+fn bench_query_params(c: &mut Criterion) {
+    c.bench_function("query_params_parse_plain", |b| {
+        b.iter(|| QueryParams::parse(black_box(PLAIN_QUERY)));
+    });
+
+    c.bench_function("query_params_parse_encoded", |b| {
+        b.iter(|| QueryParams::parse(black_box(ENCODED_QUERY)));
+    });
+
+    c.bench_function("query_params_round_trip", |b| {
+        let params = QueryParams::parse("q=rust+%26+wasm&page=2&tag=rust&tag=web");
+        b.iter(|| black_box(&params).to_query_string());
+    });
+}
+
+criterion_group!(benches, bench_decode, bench_encode, bench_query_params);
+criterion_main!(benches);
@@ -9,9 +9,9 @@ use yew_nav_link::{
     BreadcrumbItem, BreadcrumbLabelProvider, BreadcrumbLabelProviderContext, Match, NavBadge,
     NavDivider, NavDropdown, NavDropdownDivider, NavDropdownItem, NavError, NavHeader, NavIcon,
     NavIconSize, NavItem, NavLink, NavLinkWithIcon, NavList, NavResult, NavTab, NavTabPanel,
-    NavTabs, NavText, Navigation, Pagination, is_absolute, join_paths, nav_link, normalize_path,
-    use_breadcrumbs, use_is_active, use_is_exact_active, use_is_partial_active, use_navigation,
-    use_query_params, use_route_info,
+    NavTabs, NavText, Navigation, Pagination, RouteInfo, is_absolute, join_paths, nav_link,
+    normalize_path, use_breadcrumbs, use_is_active, use_is_exact_active, use_is_partial_active,
+    use_navigation, use_query_params, use_route_info,
     utils::{urlencoding_decode, urlencoding_encode}
 };
 use yew_router::prelude::*;
@@ -453,7 +453,9 @@ fn NavLinkPage() -> Html {
 fn NavLinkLab() -> Html {
     let current = use_route::<Route>();
     let slug = match current.clone() {
-        Some(Route::NavLinkLab { slug }) => slug,
+        Some(Route::NavLinkLab {
+            slug
+        }) => slug,
         _ => "stay".into()
     };
 
@@ -758,8 +760,8 @@ html! {
 
 #[function_component]
 fn HooksPage() -> Html {
-    let route_info: Option<Route> = use_route_info::<Route>();
-    let is_active_home = use_is_active(Route::Home);
+    let route_info: RouteInfo<Route> = use_route_info::<Route>();
+    let is_active_home = use_is_active(Route::Home, Match::Exact);
     let is_exact_active_hooks = use_is_exact_active(Route::Hooks);
     let is_partial_active_hooks = use_is_partial_active(Route::Hooks);
     let query = use_query_params();
@@ -767,7 +769,9 @@ fn HooksPage() -> Html {
     let trail: Vec<BreadcrumbItem<Route>> = use_breadcrumbs();
 
     let push_home = nav.push_callback(Route::Home).reform(|_: MouseEvent| ());
-    let replace_components = nav.replace_callback(Route::Components).reform(|_: MouseEvent| ());
+    let replace_components = nav
+        .replace_callback(Route::Components)
+        .reform(|_: MouseEvent| ());
     let go_back = nav.go_back.clone().reform(|_: MouseEvent| ());
     let go_forward = nav.go_forward.clone().reform(|_: MouseEvent| ());
 
@@ -785,7 +789,7 @@ fn HooksPage() -> Html {
                         "Right now Home is active = {}, Hooks (exact) = {}, Hooks (partial) = {}.",
                         is_active_home, is_exact_active_hooks, is_partial_active_hooks
                     ))}
-                    code={r#"let is_home          = use_is_active(Route::Home);
+                    code={r#"let is_home          = use_is_active(Route::Home, Match::Exact);
 let is_exact_hooks   = use_is_exact_active(Route::Hooks);
 let is_partial_hooks = use_is_partial_active(Route::Hooks);"#}
                 >
@@ -805,11 +809,19 @@ let is_partial_hooks = use_is_partial_active(Route::Hooks);"#}
 
             <PageSection title="use_route_info">
                 <DemoCard
-                    title="The current route as Option<R>"
-                    description={AttrValue::from(format!("Current route: {:?}", route_info))}
-                    code={r#"let current: Option<Route> = use_route_info::<Route>();"#}
+                    title="The current route and URL as RouteInfo<R>"
+                    description={AttrValue::from(format!(
+                        "Current route: {:?}, matched by {:?}, not found = {}.",
+                        route_info.route, route_info.template, route_info.is_not_found
+                    ))}
+                    code={r#"let info: RouteInfo<Route> = use_route_info::<Route>();
+let route    = info.route;        // Option<Route>
+let path     = info.route_path;   // path without the basename
+let page     = info.query.get("page");
+let fragment = info.fragment;     // Option<String>, without '#'
+let template = info.template;     // e.g. Some("/hooks")"#}
                 >
-                    <code class="inline-code">{ format!("{:?}", route_info) }</code>
+                    <code class="inline-code">{ format!("{} {:?}", route_info.path, route_info.fragment) }</code>
                 </DemoCard>
             </PageSection>
 
@@ -840,7 +852,7 @@ let forward  = nav.go_forward.clone().reform(|_: MouseEvent| ());"#}
                     title="Reactive query string"
                     description="Append ?foo=bar&page=2 to the URL bar and watch this map update without a reload."
                     code={r#"let query: QueryParams = use_query_params();
-let page = query.get("page");"#}
+    let page = query.get("page");"#}
                 >
                     <pre class="inline-pre">{ format!("{:#?}", query) }</pre>
                 </DemoCard>
@@ -980,7 +992,7 @@ fn UtilitiesPage() -> Html {
                 <DemoCard
                     title="join_paths"
                     code={r#"join_paths("/foo/bar/", "/baz") // -> "/foo/bar/baz"
-join_paths("foo",       "bar")  // -> "foo/bar""#}
+    join_paths("foo",       "bar")  // -> "foo/bar""#}
                 >
                     <div class="util-table-wrap">
                     <table class="util-table">
@@ -1004,7 +1016,7 @@ join_paths("foo",       "bar")  // -> "foo/bar""#}
                 <DemoCard
                     title="normalize_path"
                     code={r#"normalize_path("/foo/bar/../baz/") // -> "/foo/baz/"
-normalize_path("/a/./b/c/../d")    // -> "/a/b/d""#}
+    normalize_path("/a/./b/c/../d")    // -> "/a/b/d""#}
                 >
                     <div class="util-table-wrap">
                     <table class="util-table">
@@ -1028,7 +1040,7 @@ normalize_path("/a/./b/c/../d")    // -> "/a/b/d""#}
                 <DemoCard
                     title="is_absolute"
                     code={r#"is_absolute("https://example.com") // true
-is_absolute("/relative/path")     // false"#}
+    is_absolute("/relative/path")     // false"#}
                 >
                     <div class="util-table-wrap">
                     <table class="util-table">
@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use yew_nav_link::utils::UrlParts;
+
+fuzz_target!(|data: &str| {
+    let parts = UrlParts::parse(data);
+    let serialized = parts.to_string();
+
+    assert_eq!(
+        UrlParts::parse(&serialized),
+        parts,
+        "parse -> to_string -> parse must be a fixed point"
+    );
+    assert_eq!(
+        UrlParts::parse(&serialized).to_string(),
+        serialized,
+        "serialization must be canonical"
+    );
+
+    // Without an authority only a relative path is normalized: it gains its
+    // leading `/`. Authorities may be normalized further (IPv6 compression,
+    // dropped invalid or zero-padded ports).
+    let rooted = data.is_empty() || data.starts_with(['/', '?', '#']);
+    if !data.contains("://") && rooted {
+        assert_eq!(serialized, data, "parse -> to_string must reproduce the input");
+    }
+});
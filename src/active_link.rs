@@ -1,12 +1,18 @@
 // SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+pub mod aria;
+mod group;
 pub mod mode;
 pub mod nav_link;
 pub mod props;
+pub mod state;
 pub mod utils;
 
+pub use aria::AriaCurrent;
+pub use group::{GroupRoute, NavGroup, NavGroupProps};
 pub use mode::Match;
 pub use nav_link::{NavLink, nav_link};
 pub use props::NavLinkProps;
-pub use utils::is_path_prefix;
+pub use state::{LinkState, use_link_state};
+pub use utils::{is_path_prefix, match_specificity};
@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! `aria-current` tokens.
+//!
+//! [`AriaCurrent`] selects the token an active link announces. `page` suits
+//! site navigation; step indicators, timelines, and location trails read
+//! better with `step`, `date`, or `location`. Used by
+//! [`crate::NavLink`], [`crate::LinkState`], and the pagination and
+//! breadcrumb components.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Value of the `aria-current` attribute of the current element in a set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[must_use]
+pub enum AriaCurrent {
+    /// `page`: the current page within a set of pages.
+    #[default]
+    Page,
+    /// `step`: the current step within a process.
+    Step,
+    /// `location`: the current location within an environment or context,
+    /// such as a trail of nested sections.
+    Location,
+    /// `date`: the current date within a collection of dates.
+    Date,
+    /// `time`: the current time within a set of times.
+    Time,
+    /// `true`: the current item within a set, when none of the specific
+    /// tokens fit.
+    True
+}
+
+impl AriaCurrent {
+    /// Returns the attribute value, e.g. `"page"`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Page => "page",
+            Self::Step => "step",
+            Self::Location => "location",
+            Self::Date => "date",
+            Self::Time => "time",
+            Self::True => "true"
+        }
+    }
+
+    /// Returns the attribute value when `is_current`, or `None` so the
+    /// attribute is omitted.
+    #[must_use]
+    pub const fn when(self, is_current: bool) -> Option<&'static str> {
+        if is_current {
+            Some(self.as_str())
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for AriaCurrent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_page() {
+        assert_eq!(AriaCurrent::default(), AriaCurrent::Page);
+    }
+
+    #[test]
+    fn tokens_match_the_aria_spec() {
+        let tokens = [
+            AriaCurrent::Page,
+            AriaCurrent::Step,
+            AriaCurrent::Location,
+            AriaCurrent::Date,
+            AriaCurrent::Time,
+            AriaCurrent::True
+        ]
+        .map(AriaCurrent::as_str);
+        assert_eq!(tokens, ["page", "step", "location", "date", "time", "true"]);
+    }
+
+    #[test]
+    fn when_omits_the_attribute_for_non_current_items() {
+        assert_eq!(AriaCurrent::Step.when(true), Some("step"));
+        assert_eq!(AriaCurrent::Step.when(false), None);
+    }
+
+    #[test]
+    fn display_writes_the_token() {
+        assert_eq!(AriaCurrent::Location.to_string(), "location");
+    }
+}
@@ -0,0 +1,370 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! # `NavGroup`
+//!
+//! Exclusive active state for sibling [`NavLink`](crate::NavLink)s. On
+//! `/docs/api/x`, partial links to `/docs` and `/docs/api` are both active
+//! on their own; inside a `NavGroup` only the most specific one is, and the
+//! other gets the `ancestor_class` instead, like the active trail of a
+//! Drupal menu.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_nav_link::{NavGroup, NavLink};
+//! use yew_router::prelude::*;
+//!
+//! #[derive(Clone, PartialEq, Debug, Routable)]
+//! enum Route {
+//!     #[at("/docs")]
+//!     Docs,
+//!     #[at("/docs/api")]
+//!     Api
+//! }
+//!
+//! #[component]
+//! fn Sidebar() -> Html {
+//!     html! {
+//!         <NavGroup>
+//!             <NavLink<Route> to={Route::Docs} partial=true>{ "Docs" }</NavLink<Route>>
+//!             <NavLink<Route> to={Route::Api} partial=true>{ "API" }</NavLink<Route>>
+//!         </NavGroup>
+//!     }
+//! }
+//! ```
+//!
+//! # CSS Classes
+//!
+//! `NavGroup` renders no element of its own. Its links get:
+//!
+//! | Class | Condition |
+//! |-------|-----------|
+//! | `active_class` of the link | The most specific matching link of the group |
+//! | `ancestor_class` of the group | Any other link whose path is a prefix of the current path |
+//!
+//! # Props
+//!
+//! | Prop | Type | Default | Description |
+//! |------|------|---------|-------------|
+//! | `ancestor_class` | `AttrValue` | `"active-trail"` | Class of links on the active trail |
+//! | `routes` | `Vec<GroupRoute>` | `[]` | Links known to the group from the first render |
+//! | `children` | `Children` | — | Content holding the links |
+//!
+//! # How It Works
+//!
+//! Every `NavLink` under the group registers its target path and match mode
+//! in an effect and unregisters on unmount, so links can sit at any depth
+//! (inside `NavItem`s, dropdowns, …). A link is active when it matches on
+//! its own and no registered or declared matching link of the group ranks
+//! higher by [`match_specificity`]: an equal path beats any prefix, and a
+//! deeper prefix beats a shallower one. Links of equal rank are active
+//! together.
+//!
+//! # Server Rendering
+//!
+//! Effects do not run on the server, and on the first client render no
+//! link has registered yet. Links listed in `routes` take part in the
+//! selection from the start, so server output, the hydrating render, and
+//! the settled page agree:
+//!
+//! ```rust
+//! # use yew::prelude::*;
+//! # use yew_nav_link::{GroupRoute, Match, NavGroup, NavLink};
+//! # use yew_router::prelude::*;
+//! # #[derive(Clone, PartialEq, Debug, Routable)]
+//! # enum Route {
+//! #     #[at("/docs")]
+//! #     Docs,
+//! #     #[at("/docs/api")]
+//! #     Api
+//! # }
+//! #[component]
+//! fn Sidebar() -> Html {
+//!     let routes = vec![
+//!         GroupRoute::new(&Route::Docs, Match::Partial),
+//!         GroupRoute::new(&Route::Api, Match::Partial),
+//!     ];
+//!     html! {
+//!         <NavGroup {routes}>
+//!             <NavLink<Route> to={Route::Docs} partial=true>{ "Docs" }</NavLink<Route>>
+//!             <NavLink<Route> to={Route::Api} partial=true>{ "API" }</NavLink<Route>>
+//!         </NavGroup>
+//!     }
+//! }
+//! ```
+
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_router::Routable;
+
+use super::{
+    mode::Match,
+    utils::{is_path_prefix, match_specificity}
+};
+
+/// One link registered with a [`NavGroup`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupTarget {
+    path:    String,
+    partial: bool
+}
+
+impl GroupTarget {
+    /// Describes a link to `path`, matched partially when `partial`.
+    pub const fn new(path: String, partial: bool) -> Self {
+        Self {
+            path,
+            partial
+        }
+    }
+
+    /// Ranks the match of this link against `current`, or `None` when the
+    /// link is not active on its own.
+    fn rank(&self, current: &str) -> Option<usize> {
+        match_specificity(&self.path, current).filter(|&rank| self.partial || rank == usize::MAX)
+    }
+}
+
+/// A link declared up front through [`NavGroupProps::routes`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupRoute(GroupTarget);
+
+impl GroupRoute {
+    /// Declares a link to `route` matched under `mode`, as the grouped
+    /// `NavLink` to it is.
+    #[must_use]
+    pub fn new<R: Routable>(route: &R, mode: Match) -> Self {
+        Self(GroupTarget::new(route.to_path(), mode == Match::Partial))
+    }
+}
+
+/// Returns `(is_active, is_ancestor)` for the link `target` among `group`:
+/// active when no link of the group outranks it, on the trail when its path
+/// is a prefix of `current` otherwise.
+fn group_state<'a>(
+    group: impl IntoIterator<Item = &'a GroupTarget>,
+    target: &GroupTarget,
+    current: &str
+) -> (bool, bool) {
+    let is_active = target.rank(current).is_some_and(|rank| {
+        group
+            .into_iter()
+            .filter_map(|other| other.rank(current))
+            .all(|other| other <= rank)
+    });
+    let is_ancestor = !is_active && is_path_prefix(&target.path, current);
+    (is_active, is_ancestor)
+}
+
+/// Registered links of a [`NavGroup`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GroupTargets {
+    targets: Vec<GroupTarget>
+}
+
+/// Registration changes dispatched by grouped links.
+pub enum GroupAction {
+    /// A link mounted or changed its target.
+    Register(GroupTarget),
+    /// A link unmounted or changed its target.
+    Unregister(GroupTarget)
+}
+
+impl GroupTargets {
+    /// Returns `(is_active, is_ancestor)` for the link `target` among the
+    /// registered links.
+    #[cfg(test)]
+    fn state(&self, target: &GroupTarget, current: &str) -> (bool, bool) {
+        group_state(&self.targets, target, current)
+    }
+}
+
+impl Reducible for GroupTargets {
+    type Action = GroupAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut targets = self.targets.clone();
+        match action {
+            GroupAction::Register(target) => targets.push(target),
+            GroupAction::Unregister(target) => {
+                if let Some(index) = targets.iter().position(|t| *t == target) {
+                    targets.remove(index);
+                }
+            }
+        }
+        Rc::new(Self {
+            targets
+        })
+    }
+}
+
+/// State a [`NavGroup`] hands to its links through context.
+#[derive(Clone, PartialEq)]
+pub struct GroupContext {
+    targets:        UseReducerHandle<GroupTargets>,
+    declared:       Vec<GroupRoute>,
+    ancestor_class: AttrValue
+}
+
+impl GroupContext {
+    /// Registers `target` and returns a closure that unregisters it again.
+    pub fn register(&self, target: GroupTarget) -> impl FnOnce() + use<> {
+        let dispatcher = self.targets.dispatcher();
+        dispatcher.dispatch(GroupAction::Register(target.clone()));
+        move || dispatcher.dispatch(GroupAction::Unregister(target))
+    }
+
+    /// Returns `(is_active, is_ancestor)` for the link `target` while
+    /// `current` is the current path.
+    pub fn state(&self, target: &GroupTarget, current: &str) -> (bool, bool) {
+        let declared = self.declared.iter().map(|route| &route.0);
+        group_state(self.targets.targets.iter().chain(declared), target, current)
+    }
+
+    /// Class for links on the active trail.
+    pub const fn ancestor_class(&self) -> &AttrValue {
+        &self.ancestor_class
+    }
+}
+
+/// Properties for the [`NavGroup`] component.
+///
+/// | Prop | Type | Default | Description |
+/// |------|------|---------|-------------|
+/// | `ancestor_class` | `AttrValue` | `"active-trail"` | Class of links on the active trail |
+/// | `routes` | `Vec<GroupRoute>` | `[]` | Links known to the group from the first render |
+/// | `children` | `Children` | — | Content holding the links |
+#[derive(Properties, Clone, PartialEq, Debug)]
+pub struct NavGroupProps {
+    /// CSS class applied to grouped links that lie on the active trail but
+    /// are not the active link.
+    #[prop_or(AttrValue::Static("active-trail"))]
+    pub ancestor_class: AttrValue,
+
+    /// Links that take part in the selection before any grouped link has
+    /// registered, i.e. on the server and on the first client render. List
+    /// the group's links here for exclusive active state in server-rendered
+    /// HTML.
+    #[prop_or_default]
+    pub routes: Vec<GroupRoute>,
+
+    /// Content holding the grouped links.
+    #[prop_or_default]
+    pub children: Children
+}
+
+impl Default for NavGroupProps {
+    fn default() -> Self {
+        Self {
+            ancestor_class: AttrValue::Static("active-trail"),
+            routes:         Vec::new(),
+            children:       Children::default()
+        }
+    }
+}
+
+/// Makes only the most specific matching [`NavLink`](crate::NavLink) among
+/// its descendants active.
+///
+/// # CSS Classes
+///
+/// - `active_class` of the link - The most specific matching link
+/// - `ancestor_class` of the group - Other links on the active trail
+#[function_component]
+pub fn NavGroup(props: &NavGroupProps) -> Html {
+    let targets = use_reducer(GroupTargets::default);
+    let context = GroupContext {
+        targets,
+        declared: props.routes.clone(),
+        ancestor_class: props.ancestor_class.clone()
+    };
+
+    html! {
+        <ContextProvider<GroupContext> {context}>
+            { for props.children.iter() }
+        </ContextProvider<GroupContext>>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(path: &str, partial: bool) -> GroupTarget {
+        GroupTarget::new(path.to_string(), partial)
+    }
+
+    fn reduce(targets: &[GroupTarget]) -> Rc<GroupTargets> {
+        targets
+            .iter()
+            .cloned()
+            .fold(Rc::new(GroupTargets::default()), |acc, target| {
+                acc.reduce(GroupAction::Register(target))
+            })
+    }
+
+    #[test]
+    fn deepest_partial_link_wins() {
+        let links = [target("/docs", true), target("/docs/api", true)];
+        let targets = reduce(&links);
+        assert_eq!(targets.state(&links[0], "/docs/api/x"), (false, true));
+        assert_eq!(targets.state(&links[1], "/docs/api/x"), (true, false));
+    }
+
+    #[test]
+    fn exact_link_only_competes_when_equal() {
+        let links = [target("/docs", true), target("/docs/api", false)];
+        let targets = reduce(&links);
+        assert_eq!(targets.state(&links[0], "/docs/api/x"), (true, false));
+        assert_eq!(targets.state(&links[1], "/docs/api/x"), (false, true));
+        assert_eq!(targets.state(&links[1], "/docs/api"), (true, false));
+        assert_eq!(targets.state(&links[0], "/docs/api"), (false, true));
+    }
+
+    #[test]
+    fn unrelated_link_is_neither_active_nor_ancestor() {
+        let links = [target("/blog", true), target("/docs", true)];
+        let targets = reduce(&links);
+        assert_eq!(targets.state(&links[0], "/docs/api"), (false, false));
+    }
+
+    #[test]
+    fn unregister_removes_one_registration() {
+        let links = [target("/docs", true), target("/docs", true)];
+        let targets = reduce(&links).reduce(GroupAction::Unregister(target("/docs", true)));
+        assert_eq!(targets.targets, [target("/docs", true)]);
+        let targets = targets.reduce(GroupAction::Unregister(target("/blog", true)));
+        assert_eq!(targets.targets.len(), 1);
+    }
+
+    #[test]
+    fn declared_routes_rank_like_registered_links() {
+        #[derive(Clone, PartialEq, Debug, Routable)]
+        enum Route {
+            #[at("/docs")]
+            Docs,
+            #[at("/docs/api")]
+            Api
+        }
+        let declared = [
+            GroupRoute::new(&Route::Docs, Match::Partial),
+            GroupRoute::new(&Route::Api, Match::Partial)
+        ];
+        let group = declared.iter().map(|route| &route.0);
+        assert_eq!(
+            group_state(group, &target("/docs", true), "/docs/api/x"),
+            (false, true)
+        );
+        assert_eq!(declared[1], GroupRoute(target("/docs/api", true)));
+    }
+
+    #[test]
+    fn nav_group_props_default() {
+        let props = NavGroupProps::default();
+        assert_eq!(props.ancestor_class, "active-trail");
+        assert!(props.routes.is_empty());
+    }
+}
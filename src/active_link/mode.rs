@@ -4,11 +4,15 @@
 //! Active-state matching strategy.
 //!
 //! [`Match`] selects between exact equality and segment-wise prefix matching.
-//! Used by [`crate::NavLink`] and the [`crate::nav_link()`] function-syntax
-//! helper.
+//! Used by [`crate::NavLink`], the [`crate::nav_link()`] function-syntax
+//! helper, and [`crate::use_is_active`].
 
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use yew_router::Routable;
+
+use super::utils::is_path_prefix;
+
 /// Path matching strategy for `NavLink` active state detection.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[must_use]
@@ -20,6 +24,22 @@ pub enum Match {
     Partial
 }
 
+impl Match {
+    /// Returns `true` when `target` is active while `current` is the
+    /// current route: equal routes for [`Match::Exact`], a segment-wise path
+    /// prefix (see [`is_path_prefix`]) for [`Match::Partial`].
+    #[must_use]
+    pub fn is_active<R>(self, target: &R, current: &R) -> bool
+    where
+        R: Routable + PartialEq
+    {
+        match self {
+            Self::Exact => target == current,
+            Self::Partial => is_path_prefix(&target.to_path(), &current.to_path())
+        }
+    }
+}
+
 impl Display for Match {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
@@ -33,6 +53,30 @@ impl Display for Match {
 mod tests {
     use super::*;
 
+    #[derive(Clone, PartialEq, Debug, Routable)]
+    enum Route {
+        #[at("/")]
+        Home,
+        #[at("/docs")]
+        Docs,
+        #[at("/docs/api")]
+        Api
+    }
+
+    #[test]
+    fn exact_is_active_only_for_equal_routes() {
+        assert!(Match::Exact.is_active(&Route::Docs, &Route::Docs));
+        assert!(!Match::Exact.is_active(&Route::Docs, &Route::Api));
+    }
+
+    #[test]
+    fn partial_is_active_for_nested_routes_but_root_stays_exact() {
+        assert!(Match::Partial.is_active(&Route::Docs, &Route::Api));
+        assert!(!Match::Partial.is_active(&Route::Api, &Route::Docs));
+        assert!(!Match::Partial.is_active(&Route::Home, &Route::Docs));
+        assert!(Match::Partial.is_active(&Route::Home, &Route::Home));
+    }
+
     #[test]
     fn match_default_is_exact() {
         assert_eq!(Match::default(), Match::Exact);
@@ -5,16 +5,12 @@
 //!
 //! Both build on the same active-state algorithm: compare the current route
 //! to the target on every render and toggle the `active` class plus
-//! `aria-current="page"` accordingly.
+//! `aria-current` accordingly.
 
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-use super::{
-    mode::Match,
-    props::NavLinkProps,
-    utils::{build_class, is_path_prefix}
-};
+use super::{mode::Match, props::NavLinkProps, state::use_link_state};
 
 /// Navigation link with automatic active-state detection.
 ///
@@ -25,67 +21,34 @@ use super::{
 ///
 /// When the route matches, the rendered anchor gains:
 /// - the `active` class (or whatever `active_class` overrides it with), and
-/// - `aria-current="page"` so screen readers announce the current location.
+/// - `aria-current="page"` (or the token set through `aria_current`) so screen
+///   readers announce the current location.
+///
+/// Inside a [`NavGroup`](crate::NavGroup) only the most specific matching
+/// link of the group is active; other links on the active trail get the
+/// group's `ancestor_class` and no `aria-current`.
 ///
 /// Outside a router context (no [`Navigator`] in scope) the component
 /// degrades to a plain anchor: the href is the bare route path and clicks
 /// follow default browser navigation instead of being suppressed.
 #[component]
 pub fn NavLink<R: Routable + PartialEq + Clone + 'static>(props: &NavLinkProps<R>) -> Html {
-    let current_route = use_route::<R>();
-    let navigator = use_navigator();
-    let is_active = current_route.is_some_and(|route| {
-        if props.partial {
-            is_path_prefix(&props.to.to_path(), &route.to_path())
-        } else {
-            route == props.to
-        }
-    });
-
-    let href = resolved_href(navigator.as_ref(), props.to.to_path());
-
-    let onclick = {
-        let to = props.to.clone();
-        Callback::from(move |event: MouseEvent| {
-            if is_modified_click(&event) {
-                return;
-            }
-            if let Some(nav) = &navigator {
-                event.prevent_default();
-                nav.push(&to);
-            }
-        })
+    let mode = if props.partial {
+        Match::Partial
+    } else {
+        Match::Exact
     };
-
-    let class = build_class(is_active, &props.class, &props.active_class);
-    let aria_current = if is_active { Some("page") } else { None };
+    let link = use_link_state(props.to.clone(), mode);
+    let class = link.class_with(&props.class, &props.active_class);
+    let aria_current = link.aria_current_with(props.aria_current);
 
     html! {
-        <a class={class} href={href} onclick={onclick} aria-current={aria_current}>
+        <a class={class} href={link.href} onclick={link.onclick} aria-current={aria_current}>
             { for props.children.iter() }
         </a>
     }
 }
 
-/// Builds the displayed href, prefixing the router basename when one is set
-/// (e.g. `/yew-nav-link` on GitHub Pages).
-///
-/// Without a `Navigator` in scope the bare route path is returned; it stays a
-/// valid relative anchor, and the click handler then leaves default browser
-/// navigation intact so the link keeps working outside a router context.
-fn resolved_href(navigator: Option<&Navigator>, path: String) -> String {
-    match navigator.and_then(Navigator::basename) {
-        Some(base) if !base.is_empty() => format!("{base}{path}"),
-        _ => path
-    }
-}
-
-/// Returns `true` for clicks carrying a modifier key, which must fall through
-/// to the browser to preserve "open in new tab / window" affordances.
-fn is_modified_click(event: &MouseEvent) -> bool {
-    event.meta_key() || event.ctrl_key() || event.shift_key() || event.alt_key()
-}
-
 /// Creates a `NavLink` with the specified match mode for plain-text labels.
 ///
 /// `match_mode` selects between exact and prefix matching:
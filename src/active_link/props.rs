@@ -6,6 +6,8 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use super::aria::AriaCurrent;
+
 /// Properties for the [`crate::NavLink`] component.
 #[derive(Properties, Clone, PartialEq, Debug)]
 pub struct NavLinkProps<R: Routable + PartialEq + Clone + 'static> {
@@ -28,7 +30,11 @@ pub struct NavLinkProps<R: Routable + PartialEq + Clone + 'static> {
 
     /// CSS class applied when the link is active.
     #[prop_or(AttrValue::Static("active"))]
-    pub active_class: AttrValue
+    pub active_class: AttrValue,
+
+    /// `aria-current` token emitted when the link is active.
+    #[prop_or_default]
+    pub aria_current: AriaCurrent
 }
 
 #[cfg(test)]
@@ -48,7 +54,8 @@ mod tests {
             children:     Children::default(),
             partial:      false,
             class:        AttrValue::Static("nav-link"),
-            active_class: AttrValue::Static("active")
+            active_class: AttrValue::Static("active"),
+            aria_current: AriaCurrent::Page
         };
         let props2 = props1.clone();
         assert_eq!(props1, props2);
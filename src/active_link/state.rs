@@ -0,0 +1,267 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! # `use_link_state`
+//!
+//! Everything [`NavLink`](crate::NavLink) computes for its anchor, as a
+//! value. Custom link components (a button that looks like a tab, a card
+//! that links somewhere) call [`use_link_state`] and render their own
+//! element while keeping the active state, `aria-current`, basename-aware
+//! href, and click handling of a `NavLink`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_nav_link::{Match, use_link_state};
+//! use yew_router::prelude::*;
+//!
+//! #[derive(Clone, PartialEq, Debug, Routable)]
+//! enum Route {
+//!     #[at("/docs")]
+//!     Docs
+//! }
+//!
+//! #[component]
+//! fn DocsCard() -> Html {
+//!     let link = use_link_state(Route::Docs, Match::Partial);
+//!     html! {
+//!         <a
+//!             class={link.class_with("card", "card-current")}
+//!             href={link.href.clone()}
+//!             onclick={link.onclick.clone()}
+//!             aria-current={link.aria_current}
+//!         >
+//!             { "Documentation" }
+//!         </a>
+//!     }
+//! }
+//! ```
+//!
+//! Outside a router the state degrades like a `NavLink` does: the link is
+//! inactive, the href is the bare route path, and `onclick` leaves the
+//! browser's default navigation alone.
+
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use super::{
+    aria::AriaCurrent,
+    group::{GroupContext, GroupTarget},
+    mode::Match,
+    utils::{build_class, is_modified_click}
+};
+use crate::{
+    events::{Classifier, NavEvent, NavEvents, NavigationDirection},
+    utils::with_basename
+};
+
+/// The state of a link to some route, returned by [`use_link_state`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkState {
+    /// `true` when the link is active under its [`Match`] mode, or, inside a
+    /// [`NavGroup`](crate::NavGroup), when it is the group's active link.
+    pub is_active:    bool,
+    /// `true` when a [`NavGroup`](crate::NavGroup) puts the link on the
+    /// active trail instead of making it active.
+    pub is_ancestor:  bool,
+    /// `Some("page")` when active, for the `aria-current` attribute; see
+    /// [`LinkState::aria_current_with`] for other tokens.
+    pub aria_current: Option<&'static str>,
+    /// `nav-link`, plus `active` or the group's ancestor class.
+    pub class:        String,
+    /// Browser href of the route, prefixed with the router basename.
+    pub href:         String,
+    /// Click handler: routes plain left-clicks through the `Navigator` and
+    /// leaves modifier-clicks to the browser.
+    pub onclick:      Callback<MouseEvent>,
+    ancestor_class:   Option<AttrValue>
+}
+
+impl LinkState {
+    /// Returns the class list for custom `base` and `active` classes: `base`
+    /// plus `active` when active, or plus the group's ancestor class when on
+    /// the active trail.
+    #[must_use]
+    pub fn class_with(&self, base: &str, active: &str) -> String {
+        self.ancestor_class.as_ref().map_or_else(
+            || build_class(self.is_active, base, active),
+            |ancestor| build_class(true, base, ancestor)
+        )
+    }
+
+    /// Returns the `aria-current` value for `token` when active, e.g.
+    /// `Some("step")` for [`AriaCurrent::Step`].
+    #[must_use]
+    pub const fn aria_current_with(&self, token: AriaCurrent) -> Option<&'static str> {
+        token.when(self.is_active)
+    }
+}
+
+/// Returns the state of a link to `to` under `mode`, exactly as a
+/// [`NavLink`](crate::NavLink) with that mode would compute it.
+///
+/// Inside a [`NavGroup`](crate::NavGroup) the link takes part in the
+/// group's longest-match selection, and inside a
+/// [`NavEventsProvider`](crate::NavEventsProvider) `onclick` publishes
+/// [`NavEvent::LinkClicked`].
+#[hook]
+pub fn use_link_state<R>(to: R, mode: Match) -> LinkState
+where
+    R: Routable + PartialEq + Clone + 'static
+{
+    use_link_state_in(to, mode, true)
+}
+
+/// [`use_link_state`] for links that stay out of an enclosing
+/// [`NavGroup`](crate::NavGroup)'s selection, such as breadcrumb links: they
+/// neither register with the group nor take its active or ancestor state.
+#[hook]
+pub(crate) fn use_ungrouped_link_state<R>(to: R, mode: Match) -> LinkState
+where
+    R: Routable + PartialEq + Clone + 'static
+{
+    use_link_state_in(to, mode, false)
+}
+
+/// Shared body of [`use_link_state`] and [`use_ungrouped_link_state`].
+#[hook]
+fn use_link_state_in<R>(to: R, mode: Match, grouped: bool) -> LinkState
+where
+    R: Routable + PartialEq + Clone + 'static
+{
+    let current_route = use_route::<R>();
+    let navigator = use_navigator();
+    let group = use_context::<GroupContext>().filter(|_| grouped);
+    let events = use_context::<NavEvents<R>>();
+    let classifier = use_context::<Classifier>();
+    let target = GroupTarget::new(to.to_path(), mode == Match::Partial);
+    {
+        let group = group.clone();
+        use_effect_with(target.clone(), move |target| {
+            let unregister = group.map(|group| group.register(target.clone()));
+            move || {
+                if let Some(unregister) = unregister {
+                    unregister();
+                }
+            }
+        });
+    }
+    let (is_active, is_ancestor) = match (&group, &current_route) {
+        (Some(group), Some(route)) => group.state(&target, &route.to_path()),
+        _ => (
+            current_route.is_some_and(|route| mode.is_active(&to, &route)),
+            false
+        )
+    };
+
+    let href = resolved_href(navigator.as_ref(), to.to_path());
+
+    let onclick = Callback::from(move |event: MouseEvent| {
+        let modifier = is_modified_click(&event);
+        if let Some(events) = &events {
+            events.emit(&NavEvent::LinkClicked {
+                target: to.clone(),
+                modifier
+            });
+        }
+        if modifier {
+            return;
+        }
+        if let Some(nav) = &navigator {
+            event.prevent_default();
+            if let Some(classifier) = &classifier {
+                classifier.expect(NavigationDirection::Push);
+            }
+            nav.push(&to);
+        }
+    });
+
+    let ancestor_class = group
+        .filter(|_| is_ancestor)
+        .map(|group| group.ancestor_class().clone());
+    let mut state = LinkState {
+        is_active,
+        is_ancestor,
+        aria_current: AriaCurrent::Page.when(is_active),
+        class: String::new(),
+        href,
+        onclick,
+        ancestor_class
+    };
+    state.class = state.class_with("nav-link", "active");
+    state
+}
+
+/// Builds the displayed href, prefixing the router basename when one is set
+/// (e.g. `/yew-nav-link` on GitHub Pages).
+///
+/// Without a `Navigator` in scope the bare route path is returned; it stays a
+/// valid relative anchor, and the click handler then leaves default browser
+/// navigation intact so the link keeps working outside a router context.
+fn resolved_href(navigator: Option<&Navigator>, path: String) -> String {
+    match navigator.and_then(Navigator::basename) {
+        Some(base) => with_basename(&path, base),
+        None => path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq, Debug, Routable)]
+    enum TestRoute {
+        #[at("/docs")]
+        Docs
+    }
+
+    fn state(is_active: bool, ancestor_class: Option<&'static str>) -> LinkState {
+        LinkState {
+            is_active,
+            is_ancestor: ancestor_class.is_some(),
+            aria_current: is_active.then_some("page"),
+            class: String::new(),
+            href: "/docs".to_string(),
+            onclick: Callback::noop(),
+            ancestor_class: ancestor_class.map(AttrValue::Static)
+        }
+    }
+
+    #[test]
+    fn class_with_uses_custom_classes() {
+        assert_eq!(state(true, None).class_with("tab", "tab-on"), "tab tab-on");
+        assert_eq!(state(false, None).class_with("tab", "tab-on"), "tab");
+    }
+
+    #[test]
+    fn class_with_prefers_ancestor_class() {
+        assert_eq!(
+            state(false, Some("active-trail")).class_with("tab", "tab-on"),
+            "tab active-trail"
+        );
+    }
+
+    #[test]
+    fn aria_current_with_uses_the_token_only_when_active() {
+        assert_eq!(
+            state(true, None).aria_current_with(AriaCurrent::Step),
+            Some("step")
+        );
+        assert_eq!(
+            state(false, None).aria_current_with(AriaCurrent::Step),
+            None
+        );
+    }
+
+    #[test]
+    fn resolved_href_without_navigator_is_bare_path() {
+        assert_eq!(resolved_href(None, "/docs".to_string()), "/docs");
+    }
+
+    #[test]
+    fn use_link_state_is_callable() {
+        let _ = use_link_state(TestRoute::Docs, Match::Partial);
+        let _ = use_ungrouped_link_state(TestRoute::Docs, Match::Exact);
+    }
+}
@@ -1,11 +1,21 @@
 // SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-//! Helpers shared between [`crate::NavLink`] and its callers: segment-wise
-//! path-prefix matching and the active-class string builder.
+//! Helpers shared between [`crate::NavLink`] and its callers.
+//!
+//! Segment-wise path-prefix matching and its specificity ranking, the
+//! active-class string builder, and click classification for
+//! router-intercepted anchors.
+
+use yew::MouseEvent;
+
+use crate::utils::{path_segments, segments_eq};
 
 /// Checks if `target` path is a segment-wise prefix of `current` path.
 ///
+/// Segments are split with [`path_segments`] and compared with
+/// percent-encoding normalized, so `/caf%C3%A9` prefixes `/café/menu`.
+///
 /// A root or empty `target` (zero non-empty segments) matches only a root or
 /// empty `current`. Without this rule `"/"` would prefix every path and a
 /// partial-matching Home link would be active on every page; React Router
@@ -13,13 +23,12 @@
 #[inline]
 #[must_use]
 pub fn is_path_prefix(target: &str, current: &str) -> bool {
-    let mut target_iter = target.split('/').filter(|s| !s.is_empty());
-    let mut current_iter = current.split('/').filter(|s| !s.is_empty());
+    let mut current_iter = path_segments(current);
 
     let mut matched_any = false;
-    for t in target_iter.by_ref() {
+    for t in path_segments(target) {
         match current_iter.next() {
-            Some(c) if t == c => matched_any = true,
+            Some(c) if segments_eq(t, c) => matched_any = true,
             _ => return false
         }
     }
@@ -27,6 +36,40 @@ pub fn is_path_prefix(target: &str, current: &str) -> bool {
     matched_any || current_iter.next().is_none()
 }
 
+/// Ranks how specifically `target` matches `current`, for picking one
+/// active link among several matching ones.
+///
+/// Returns `None` when `target` is not a segment-wise prefix of `current`
+/// (see [`is_path_prefix`]), `Some(usize::MAX)` when the paths are equal, and
+/// otherwise the number of segments in `target`, so deeper prefixes rank
+/// higher.
+///
+/// # Examples
+///
+/// ```
+/// use yew_nav_link::active_link::utils::match_specificity;
+///
+/// assert_eq!(match_specificity("/docs", "/docs/api/v1"), Some(1));
+/// assert_eq!(match_specificity("/docs/api", "/docs/api/v1"), Some(2));
+/// assert_eq!(
+///     match_specificity("/docs/api/v1", "/docs/api/v1"),
+///     Some(usize::MAX)
+/// );
+/// assert_eq!(match_specificity("/blog", "/docs"), None);
+/// ```
+#[must_use]
+pub fn match_specificity(target: &str, current: &str) -> Option<usize> {
+    if !is_path_prefix(target, current) {
+        return None;
+    }
+    let depth = path_segments(target).count();
+    Some(if depth == path_segments(current).count() {
+        usize::MAX
+    } else {
+        depth
+    })
+}
+
 /// Combines a base CSS class name with an active class name when selected.
 ///
 /// When `is_active` is `true`, returns both the base and active classes
@@ -50,6 +93,12 @@ pub fn build_class(is_active: bool, base_class: &str, active_class: &str) -> Str
     }
 }
 
+/// Returns `true` for clicks carrying a modifier key, which must fall through
+/// to the browser to preserve "open in new tab / window" affordances.
+pub(crate) fn is_modified_click(event: &MouseEvent) -> bool {
+    event.meta_key() || event.ctrl_key() || event.shift_key() || event.alt_key()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +134,14 @@ mod tests {
         assert!(!is_path_prefix("/doc", "/documents"));
     }
 
+    #[test]
+    fn specificity_ranks_equal_paths_highest() {
+        assert_eq!(match_specificity("/", "/"), Some(usize::MAX));
+        assert_eq!(match_specificity("/docs/", "/docs"), Some(usize::MAX));
+        assert_eq!(match_specificity("/", "/docs"), None);
+        assert!(match_specificity("/a", "/a/b/c") < match_specificity("/a/b", "/a/b/c"));
+    }
+
     #[test]
     fn prefix_root_target_matches_only_root() {
         assert!(!is_path_prefix("/", "/docs"));
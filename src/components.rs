@@ -7,6 +7,7 @@
 //! interfaces, including badges, dropdowns, tabs, pagination, and more.
 
 mod badge;
+mod breadcrumbs;
 mod dropdown;
 mod focus;
 mod header;
@@ -21,6 +22,7 @@ mod tabs;
 mod text;
 
 pub use badge::{NavBadge, NavBadgeProps};
+pub use breadcrumbs::{BreadcrumbSchema, Breadcrumbs, BreadcrumbsProps};
 pub use dropdown::{
     NavDropdown, NavDropdownDivider, NavDropdownDividerProps, NavDropdownItem,
     NavDropdownItemProps, NavDropdownProps
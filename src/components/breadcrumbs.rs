@@ -0,0 +1,497 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! # Breadcrumbs
+//!
+//! Breadcrumb trail for the current route, built with
+//! [`use_breadcrumbs`](crate::use_breadcrumbs). Each crumb renders as a
+//! link with the state of [`use_link_state`](crate::use_link_state), kept
+//! out of any enclosing [`NavGroup`](crate::NavGroup), so the last one
+//! carries `aria-current="page"` and, optionally, the ancestors
+//! `aria-current="location"`; crumbs for path prefixes that are not routes of
+//! their own render as plain text instead of linking to the wrong page. Long
+//! trails collapse their middle crumbs into an expandable `…`, and the trail
+//! can be annotated with a schema.org `BreadcrumbList` for search engines.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_nav_link::components::{BreadcrumbSchema, Breadcrumbs};
+//! use yew_router::prelude::*;
+//!
+//! #[derive(Clone, PartialEq, Debug, Routable)]
+//! enum Route {
+//!     #[at("/")]
+//!     Home,
+//!     #[at("/docs")]
+//!     Docs
+//! }
+//!
+//! #[component]
+//! fn Trail() -> Html {
+//!     html! {
+//!         <Breadcrumbs<Route>
+//!             separator={html! { "›" }}
+//!             max_items={Some(4)}
+//!             schema={BreadcrumbSchema::JsonLd}
+//!         />
+//!     }
+//! }
+//! ```
+//!
+//! # CSS Classes
+//!
+//! | Class | Condition |
+//! |-------|-----------|
+//! | `breadcrumbs` | Container `<nav>` element |
+//! | `breadcrumb-list` | The `<ol>` holding the crumbs |
+//! | `breadcrumb-item` | Each crumb `<li>` |
+//! | `active` | Applied to the last crumb |
+//! | `breadcrumb-separator` | `<span>` before every crumb but the first |
+//! | `breadcrumb-text` | `<span>` of an unrecognized crumb, rendered instead of a link |
+//! | `breadcrumb-ellipsis` | `<li>` holding the `…` expand button |
+//!
+//! # Props
+//!
+//! | Prop | Type | Default | Description |
+//! |------|------|---------|-------------|
+//! | `separator` | `Html` | `"/"` | Content rendered between crumbs |
+//! | `max_items` | `Option<usize>` | `None` | Crumbs shown before the middle collapses |
+//! | `schema` | `BreadcrumbSchema` | `None` | schema.org `BreadcrumbList` output |
+//! | `base_url` | `Option<AttrValue>` | `None` | Origin that makes schema.org item URLs absolute |
+//! | `aria_label` | `AttrValue` | `"breadcrumb"` | Accessible name of the `<nav>` |
+//! | `aria_current` | `AriaCurrent` | `Page` | `aria-current` token of the last crumb |
+//! | `ancestor_aria_current` | `Option<AriaCurrent>` | `None` | `aria-current` token of the other crumbs |
+//! | `classes` | `Classes` | — | Additional CSS classes |
+
+use std::fmt::Write as _;
+
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::{
+    AriaCurrent, Match,
+    active_link::state::use_ungrouped_link_state,
+    components::{NavIcon, NavIconSize},
+    hooks::BreadcrumbItem,
+    use_breadcrumbs,
+    utils::UrlParts
+};
+
+const LIST_ITEM_TYPE: &str = "https://schema.org/ListItem";
+const LIST_TYPE: &str = "https://schema.org/BreadcrumbList";
+
+/// schema.org `BreadcrumbList` output of [`Breadcrumbs`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BreadcrumbSchema {
+    /// No structured data.
+    #[default]
+    None,
+    /// `itemscope`/`itemprop` microdata on the rendered list. Collapsed
+    /// crumbs are not in the markup, so they are absent from the data.
+    Microdata,
+    /// A `<script type="application/ld+json">` block listing every crumb,
+    /// collapsed or not.
+    ///
+    /// Both forms omit the `item` URL of unrecognized crumbs.
+    JsonLd
+}
+
+/// Properties for the [`Breadcrumbs`] component.
+///
+/// | Prop | Type | Default | Description |
+/// |------|------|---------|-------------|
+/// | `separator` | `Html` | `"/"` | Content rendered between crumbs |
+/// | `max_items` | `Option<usize>` | `None` | Crumbs shown before the middle collapses |
+/// | `schema` | `BreadcrumbSchema` | `None` | schema.org `BreadcrumbList` output |
+/// | `base_url` | `Option<AttrValue>` | `None` | Origin that makes schema.org item URLs absolute |
+/// | `aria_label` | `AttrValue` | `"breadcrumb"` | Accessible name of the `<nav>` |
+/// | `aria_current` | `AriaCurrent` | `Page` | `aria-current` token of the last crumb |
+/// | `ancestor_aria_current` | `Option<AriaCurrent>` | `None` | `aria-current` token of the other crumbs |
+/// | `classes` | `Classes` | — | Additional CSS classes |
+#[derive(Properties, Clone, PartialEq, Debug)]
+pub struct BreadcrumbsProps {
+    /// Additional CSS classes applied to the `<nav>` container.
+    #[prop_or_default]
+    pub classes: Classes,
+
+    /// Content rendered between crumbs, hidden from assistive technology.
+    #[prop_or_else(default_separator)]
+    pub separator: Html,
+
+    /// Maximum number of crumbs shown. Longer trails keep the first crumb
+    /// and the last `max_items - 1`, replacing the rest with a `…` button
+    /// that expands the full trail until the route changes. Values below `2`
+    /// are treated as `2`.
+    #[prop_or_default]
+    pub max_items: Option<usize>,
+
+    /// schema.org `BreadcrumbList` structured data to emit.
+    #[prop_or_default]
+    pub schema: BreadcrumbSchema,
+
+    /// Base URL (e.g. `https://example.com`) against which crumb hrefs are
+    /// resolved for the structured data, which expects absolute URLs.
+    /// Without it the hrefs are emitted as they are.
+    #[prop_or_default]
+    pub base_url: Option<AttrValue>,
+
+    /// Accessible name of the `<nav>` landmark.
+    #[prop_or(AttrValue::Static("breadcrumb"))]
+    pub aria_label: AttrValue,
+
+    /// `aria-current` token of the last crumb, the current page.
+    #[prop_or_default]
+    pub aria_current: AriaCurrent,
+
+    /// `aria-current` token of the crumbs before the last one. Set it to
+    /// `Some(AriaCurrent::Location)` to announce every crumb as part of the
+    /// current location; by default only the last crumb is marked.
+    #[prop_or_default]
+    pub ancestor_aria_current: Option<AriaCurrent>
+}
+
+impl Default for BreadcrumbsProps {
+    fn default() -> Self {
+        Self {
+            classes:               Classes::default(),
+            separator:             default_separator(),
+            max_items:             None,
+            schema:                BreadcrumbSchema::None,
+            base_url:              None,
+            aria_label:            AttrValue::Static("breadcrumb"),
+            aria_current:          AriaCurrent::Page,
+            ancestor_aria_current: None
+        }
+    }
+}
+
+fn default_separator() -> Html {
+    html! { "/" }
+}
+
+/// Breadcrumb trail for the current route.
+///
+/// Renders `<nav aria-label="breadcrumb"><ol>` with one `<li>` per item of
+/// [`use_breadcrumbs::<R>`](crate::use_breadcrumbs), each holding a link
+/// styled like a [`NavLink`](crate::NavLink) (or plain text when the crumb is
+/// not
+/// [recognized](BreadcrumbItem::is_recognized)) preceded by its
+/// [`icon`](BreadcrumbItem::icon), if any. Renders nothing when no route
+/// matches.
+///
+/// # CSS Classes
+///
+/// - `breadcrumbs` - Container `<nav>` element
+/// - `breadcrumb-list` - The `<ol>` holding the crumbs
+/// - `breadcrumb-item` - Each crumb `<li>`
+/// - `active` - Applied to the last crumb
+/// - `breadcrumb-separator` - `<span>` before every crumb but the first
+/// - `breadcrumb-text` - `<span>` of an unrecognized crumb, rendered instead of
+///   a link
+/// - `breadcrumb-ellipsis` - `<li>` holding the `…` expand button
+#[function_component]
+pub fn Breadcrumbs<R>(props: &BreadcrumbsProps) -> Html
+where
+    R: Routable + Clone + PartialEq + 'static
+{
+    let items = use_breadcrumbs::<R>();
+    // Expanding applies to one trail: remember whose, so the first render
+    // of a new route is already collapsed. The effect then forgets it, so
+    // coming back to that trail later starts collapsed too.
+    let expanded_path = use_state(|| None::<String>);
+    let current = items.last().map(|item| item.path.clone());
+    {
+        let expanded_path = expanded_path.clone();
+        use_effect_with(current.clone(), move |current| {
+            if expanded_path.is_some() && *expanded_path != *current {
+                expanded_path.set(None);
+            }
+        });
+    }
+
+    let Some(current) = current else {
+        return Html::default();
+    };
+    let expanded = expanded_path.as_ref() == Some(&current);
+
+    let mut classes = props.classes.clone();
+    classes.push("breadcrumbs");
+
+    let microdata = props.schema == BreadcrumbSchema::Microdata;
+    let base_url = props.base_url.as_deref();
+    let hidden = if expanded {
+        None
+    } else {
+        props
+            .max_items
+            .and_then(|max| collapsed_range(items.len(), max))
+    };
+
+    let crumb = |index: usize, item: &BreadcrumbItem<R>| {
+        let separator = (index > 0).then(|| {
+            html! {
+                <span class="breadcrumb-separator" aria-hidden="true">
+                    { props.separator.clone() }
+                </span>
+            }
+        });
+        let content = html! {
+            <>
+                if let Some(icon) = &item.icon {
+                    <NavIcon name={icon.clone()} size={NavIconSize::Small} />
+                }
+                if microdata {
+                    <span itemprop="name">{ &item.label }</span>
+                } else {
+                    { &item.label }
+                }
+            </>
+        };
+        let aria_current = if item.is_active {
+            Some(props.aria_current)
+        } else {
+            props.ancestor_aria_current
+        };
+        let link = if item.is_recognized {
+            html! {
+                <CrumbLink<R> to={item.route.clone()} {aria_current}>{ content }</CrumbLink<R>>
+            }
+        } else {
+            html! {
+                <span
+                    class="breadcrumb-text"
+                    aria-current={aria_current.map(AriaCurrent::as_str)}
+                >
+                    { content }
+                </span>
+            }
+        };
+        let class = classes!("breadcrumb-item", item.is_active.then_some("active"));
+
+        if microdata {
+            html! {
+                <li {class} itemprop="itemListElement" itemscope=true itemtype={LIST_ITEM_TYPE}>
+                    { separator }
+                    { link }
+                    if item.is_recognized {
+                        <link itemprop="item" href={item_url(&item.href, base_url)} />
+                    }
+                    <meta itemprop="position" content={(index + 1).to_string()} />
+                </li>
+            }
+        } else {
+            html! { <li {class}>{ separator }{ link }</li> }
+        }
+    };
+
+    let ellipsis = hidden.as_ref().map(|_| {
+        let expand = {
+            let expanded_path = expanded_path.clone();
+            Callback::from(move |_: MouseEvent| expanded_path.set(Some(current.clone())))
+        };
+        html! {
+            <li class="breadcrumb-item breadcrumb-ellipsis">
+                <span class="breadcrumb-separator" aria-hidden="true">
+                    { props.separator.clone() }
+                </span>
+                <button type="button" aria-label="Show all breadcrumbs" onclick={expand}>
+                    { "…" }
+                </button>
+            </li>
+        }
+    });
+
+    let (head, tail) = hidden.map_or((items.len(), items.len()), |range| (range.start, range.end));
+
+    html! {
+        <nav class={classes} aria-label={props.aria_label.clone()}>
+            <ol
+                class="breadcrumb-list"
+                itemscope={microdata}
+                itemtype={microdata.then_some(LIST_TYPE)}
+            >
+                { for items[..head].iter().enumerate().map(|(i, item)| crumb(i, item)) }
+                { ellipsis }
+                { for items[tail..].iter().enumerate().map(|(i, item)| crumb(tail + i, item)) }
+            </ol>
+            if props.schema == BreadcrumbSchema::JsonLd {
+                <script type="application/ld+json">{ json_ld(&items, base_url) }</script>
+            }
+        </nav>
+    }
+}
+
+/// Properties for [`CrumbLink`].
+#[derive(Properties, Clone, PartialEq)]
+struct CrumbLinkProps<R: Routable + PartialEq + Clone + 'static> {
+    to:           R,
+    aria_current: Option<AriaCurrent>,
+    children:     Children
+}
+
+/// A crumb's link: a `NavLink` whose `aria-current` is decided by the trail
+/// position rather than by the link's own active state. It stays out of any
+/// enclosing [`NavGroup`](crate::NavGroup), whose selection is meant for the
+/// navigation links around the trail.
+#[function_component]
+fn CrumbLink<R>(props: &CrumbLinkProps<R>) -> Html
+where
+    R: Routable + Clone + PartialEq + 'static
+{
+    let link = use_ungrouped_link_state(props.to.clone(), Match::Exact);
+    html! {
+        <a
+            class={link.class}
+            href={link.href}
+            onclick={link.onclick}
+            aria-current={props.aria_current.map(AriaCurrent::as_str)}
+        >
+            { for props.children.iter() }
+        </a>
+    }
+}
+
+/// Returns the index range of the crumbs hidden behind the ellipsis when a
+/// trail of `len` crumbs is limited to `max_items`, or `None` when it fits.
+fn collapsed_range(len: usize, max_items: usize) -> Option<std::ops::Range<usize>> {
+    let max_items = max_items.max(2);
+    (len > max_items).then(|| 1..len - (max_items - 1))
+}
+
+/// Resolves `href` against `base_url` when one is given.
+fn item_url(href: &str, base_url: Option<&str>) -> String {
+    base_url.map_or_else(
+        || href.to_string(),
+        |base| UrlParts::resolve(&UrlParts::parse(base), href).to_string()
+    )
+}
+
+/// Serializes the trail as a schema.org `BreadcrumbList` JSON-LD document.
+fn json_ld<R>(items: &[BreadcrumbItem<R>], base_url: Option<&str>) -> String {
+    let mut json = String::from(
+        r#"{"@context":"https://schema.org","@type":"BreadcrumbList","itemListElement":["#
+    );
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        let _ = write!(
+            json,
+            r#"{{"@type":"ListItem","position":{},"name":{}"#,
+            i + 1,
+            json_string(&item.label)
+        );
+        if item.is_recognized {
+            let _ = write!(
+                json,
+                r#","item":{}"#,
+                json_string(&item_url(&item.href, base_url))
+            );
+        }
+        json.push('}');
+    }
+    json.push_str("]}");
+    json
+}
+
+/// Quotes `value` as a JSON string. `<`, `>`, and `&` are escaped as well, so
+/// the text cannot close the surrounding `<script>` element.
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '<' | '>' | '&' | '\u{0}'..='\u{1f}' => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c)
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breadcrumbs_props_default() {
+        let props = BreadcrumbsProps::default();
+        assert_eq!(props.max_items, None);
+        assert_eq!(props.schema, BreadcrumbSchema::None);
+        assert_eq!(props.aria_label, "breadcrumb");
+        assert_eq!(props.separator, html! { "/" });
+        assert_eq!(props.aria_current, AriaCurrent::Page);
+        assert_eq!(props.ancestor_aria_current, None);
+    }
+
+    #[test]
+    fn collapsed_range_keeps_first_and_last_items() {
+        assert_eq!(collapsed_range(6, 3), Some(1..4));
+        assert_eq!(collapsed_range(6, 5), Some(1..2));
+        assert_eq!(collapsed_range(3, 3), None);
+        assert_eq!(collapsed_range(2, 5), None);
+    }
+
+    #[test]
+    fn collapsed_range_treats_small_limits_as_two() {
+        assert_eq!(collapsed_range(5, 0), Some(1..4));
+        assert_eq!(collapsed_range(5, 1), collapsed_range(5, 2));
+    }
+
+    #[test]
+    fn item_url_resolves_against_base() {
+        assert_eq!(item_url("/docs", None), "/docs");
+        assert_eq!(
+            item_url("/app/docs", Some("https://example.com/ignored")),
+            "https://example.com/app/docs"
+        );
+    }
+
+    #[test]
+    fn json_string_escapes_quotes_and_markup() {
+        assert_eq!(json_string(r#"say "hi"\"#), r#""say \"hi\"\\""#);
+        assert_eq!(json_string("</script>\n"), r#""\u003c/script\u003e\n""#);
+    }
+
+    #[test]
+    fn json_ld_omits_url_of_unrecognized_items() {
+        let mut users = BreadcrumbItem::fixture((), "Users", "/users", false);
+        users.is_recognized = false;
+        let items = [users, BreadcrumbItem::fixture((), "Ada", "/users/1", true)];
+        assert_eq!(
+            json_ld(&items, None),
+            concat!(
+                r#"{"@context":"https://schema.org","@type":"BreadcrumbList","itemListElement":["#,
+                r#"{"@type":"ListItem","position":1,"name":"Users"},"#,
+                r#"{"@type":"ListItem","position":2,"name":"Ada","item":"/users/1"}"#,
+                "]}"
+            )
+        );
+    }
+
+    #[test]
+    fn json_ld_lists_every_item_with_positions() {
+        let items = [
+            BreadcrumbItem::fixture((), "Home", "/", false),
+            BreadcrumbItem::fixture((), "Docs", "/docs", true)
+        ];
+        assert_eq!(
+            json_ld(&items, Some("https://example.com")),
+            concat!(
+                r#"{"@context":"https://schema.org","@type":"BreadcrumbList","itemListElement":["#,
+                r#"{"@type":"ListItem","position":1,"name":"Home","item":"https://example.com/"},"#,
+                r#"{"@type":"ListItem","position":2,"name":"Docs","item":"https://example.com/docs"}"#,
+                "]}"
+            )
+        );
+    }
+}
@@ -151,6 +151,12 @@ pub struct NavDropdownProps {
 /// menu and returns focus to the toggle, and moving focus out of the
 /// dropdown (tabbing away or clicking elsewhere) dismisses it.
 ///
+/// # Hydration
+///
+/// The menu renders closed on the server and on the first client render;
+/// open state changes only in event handlers, and focusing an item of the
+/// opened menu is an effect, so hydration never sees DOM-dependent markup.
+///
 /// # CSS Classes
 ///
 /// - `nav-dropdown` - Container `<li>` element
@@ -3,6 +3,11 @@
 
 //! Shared focus-management helpers for keyboard-navigable components
 //! ([`NavDropdown`](super::NavDropdown), [`NavTabs`](super::NavTabs)).
+//!
+//! They read the DOM through `NodeRef`s and `document.activeElement`, so they
+//! are called from event handlers and effects only, never during render:
+//! render output must stay identical to server-rendered markup for
+//! hydration.
 
 use wasm_bindgen::JsCast;
 use web_sys::{Element, HtmlElement, Node};
@@ -41,17 +41,21 @@
 //! |------|------|---------|-------------|
 //! | `active` | `bool` | `false` | Currently active page |
 //! | `disabled` | `bool` | `false` | Disabled state |
+//! | `aria_current` | `AriaCurrent` | `Page` | `aria-current` token when `active` |
 //! | `classes` | `Classes` | — | Additional CSS classes |
 //! | `children` | `Children` | — | Content |
 
 use yew::prelude::*;
 
+use crate::active_link::AriaCurrent;
+
 /// Properties for the [`PageItem`] component.
 ///
 /// | Prop | Type | Default | Description |
 /// |------|------|---------|-------------|
 /// | `active` | `bool` | `false` | Currently active page |
 /// | `disabled` | `bool` | `false` | Disabled state |
+/// | `aria_current` | `AriaCurrent` | `Page` | `aria-current` token when `active` |
 /// | `classes` | `Classes` | — | Additional CSS classes |
 /// | `children` | `Children` | — | Content |
 #[derive(Properties, Clone, PartialEq, Debug)]
@@ -68,6 +72,10 @@ pub struct PageItemProps {
     #[prop_or(false)]
     pub disabled: bool,
 
+    /// `aria-current` token of the link when `active`.
+    #[prop_or_default]
+    pub aria_current: AriaCurrent,
+
     /// Content rendered inside the page item.
     #[prop_or_default]
     pub children: Children
@@ -80,6 +88,9 @@ pub struct PageItemProps {
 /// - `page-item` - Always applied
 /// - `active` - Applied when `active` is `true`
 /// - `disabled` - Applied when `disabled` is `true`
+///
+/// An active item's `page-link` span carries `aria-current="page"` (or the
+/// `aria_current` token).
 #[function_component]
 pub fn PageItem(props: &PageItemProps) -> Html {
     let mut classes = props.classes.clone();
@@ -95,7 +106,7 @@ pub fn PageItem(props: &PageItemProps) -> Html {
 
     html! {
         <li class={classes}>
-            <span class="page-link">
+            <span class="page-link" aria-current={props.aria_current.when(props.active)}>
                 { for props.children.iter() }
             </span>
         </li>
@@ -109,10 +120,11 @@ mod tests {
     #[test]
     fn page_item_props() {
         let props = PageItemProps {
-            classes:  Classes::default(),
-            active:   false,
-            disabled: false,
-            children: Children::new(vec![])
+            classes:      Classes::default(),
+            active:       false,
+            disabled:     false,
+            aria_current: AriaCurrent::Page,
+            children:     Children::new(vec![])
         };
 
         assert!(!props.active);
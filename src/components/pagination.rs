@@ -51,11 +51,18 @@
 //! | `show_prev_next` | `bool` | `true` | Show prev/next (`‹`/`›`) buttons |
 //! | `show_first_last` | `bool` | `false` | Show first/last (`«`/`»`) jump buttons |
 //! | `on_page_change` | `Option<Callback<u32>>` | `None` | Page change callback |
+//! | `href_for` | `Option<Callback<u32, AttrValue>>` | `None` | Page href; renders controls as links |
+//! | `aria_current` | `AriaCurrent` | `Page` | `aria-current` token of the current page |
 //! | `classes` | `Classes` | — | Additional CSS classes |
 
 use yew::prelude::*;
 
 use super::pagination_page::generate_pages;
+use crate::{
+    active_link::{AriaCurrent, utils::is_modified_click},
+    hooks::use_basename,
+    utils::with_basename
+};
 
 /// Properties for the [`Pagination`] component.
 ///
@@ -67,6 +74,8 @@ use super::pagination_page::generate_pages;
 /// | `show_prev_next` | `bool` | `true` | Show prev/next (`‹`/`›`) buttons |
 /// | `show_first_last` | `bool` | `false` | Show first/last (`«`/`»`) jump buttons |
 /// | `on_page_change` | `Option<Callback<u32>>` | `None` | Page change callback |
+/// | `href_for` | `Option<Callback<u32, AttrValue>>` | `None` | Page href; renders controls as links |
+/// | `aria_current` | `AriaCurrent` | `Page` | `aria-current` token of the current page |
 /// | `classes` | `Classes` | — | Additional CSS classes |
 #[derive(Properties, Clone, PartialEq, Debug)]
 pub struct PaginationProps {
@@ -100,7 +109,22 @@ pub struct PaginationProps {
 
     /// Callback invoked with the new page number when a page is selected.
     #[prop_or_default]
-    pub on_page_change: Option<Callback<u32>>
+    pub on_page_change: Option<Callback<u32>>,
+
+    /// Builds the href of a page (e.g. `/articles?page=3`).
+    ///
+    /// When set, controls render as `<a href>` links instead of buttons, so
+    /// pages can be bookmarked and opened in a new tab. Root-relative hrefs
+    /// are prefixed with the router basename. With `on_page_change` also
+    /// set, plain left-clicks are intercepted and reported through it;
+    /// modifier-clicks fall through to the browser.
+    #[prop_or_default]
+    pub href_for: Option<Callback<u32, AttrValue>>,
+
+    /// `aria-current` token of the current page, e.g. [`AriaCurrent::Step`]
+    /// when the pages are the steps of a wizard.
+    #[prop_or_default]
+    pub aria_current: AriaCurrent
 }
 
 impl Default for PaginationProps {
@@ -112,7 +136,9 @@ impl Default for PaginationProps {
             siblings:        1,
             show_first_last: false,
             show_prev_next:  true,
-            on_page_change:  None
+            on_page_change:  None,
+            href_for:        None,
+            aria_current:    AriaCurrent::Page
         }
     }
 }
@@ -121,8 +147,12 @@ impl Default for PaginationProps {
 ///
 /// Renders a `<nav>` with page buttons and optional prev/next and
 /// first/last navigation controls. The current page stays focusable and is
-/// marked with `aria-current="page"`; ellipsis gaps render as
-/// non-interactive text, not buttons.
+/// marked with `aria-current="page"` (or the `aria_current` token);
+/// ellipsis gaps render as non-interactive text, not buttons.
+///
+/// With `href_for` set, controls render as `<a href>` links whose
+/// root-relative hrefs carry the router basename; disabled prev/next and
+/// first/last links drop their `href` and gain `aria-disabled="true"`.
 ///
 /// # CSS Classes
 ///
@@ -136,49 +166,37 @@ pub fn Pagination(props: &PaginationProps) -> Html {
     classes.push("pagination");
 
     let pages = generate_pages(props.current_page, props.total_pages, props.siblings);
-    let on_page_change = props.on_page_change.clone();
     let current_page = props.current_page;
     let total_pages = props.total_pages;
-    let show_prev_next = props.show_prev_next;
-    let show_first_last = props.show_first_last;
+    let controls = Controls {
+        on_page_change: props.on_page_change.clone(),
+        href_for:       props.href_for.clone(),
+        basename:       use_basename(),
+        aria_current:   props.aria_current
+    };
 
     html! {
         <nav aria-label="pagination">
             <ul class={classes}>
-                if show_prev_next {
+                if props.show_prev_next {
                     <li class="pagination-item">
-                        <button
-                            type="button"
-                            aria-label="Previous page"
-                            disabled={current_page <= 1}
-                            onclick={on_page_change.clone().map(move |cb| {
-                                let cb = cb.clone();
-                                move |_: MouseEvent| cb.emit(current_page.saturating_sub(1))
-                            })}
-                        >
-                            {"‹"}
-                        </button>
+                        { controls.render(
+                            current_page.saturating_sub(1),
+                            Some("Previous page"),
+                            current_page <= 1,
+                            html! { {"‹"} }
+                        ) }
                     </li>
                 }
 
-                if show_first_last {
+                if props.show_first_last {
                     <li class="pagination-item">
-                        <button
-                            type="button"
-                            aria-label="First page"
-                            disabled={current_page == 1}
-                            onclick={on_page_change.clone().map(move |cb| {
-                                let cb = cb.clone();
-                                move |_: MouseEvent| cb.emit(1)
-                            })}
-                        >
-                            {"«"}
-                        </button>
+                        { controls.render(1, Some("First page"), current_page == 1, html! { {"«"} }) }
                     </li>
                 }
 
-                { for pages.iter().map(|page| {
-                    if *page == 0 {
+                { for pages.iter().map(|&page| {
+                    if page == 0 {
                         return html! {
                             <li class="pagination-item pagination-ellipsis">
                                 <span>{"…"}</span>
@@ -186,57 +204,34 @@ pub fn Pagination(props: &PaginationProps) -> Html {
                         };
                     }
 
-                    let onclick = on_page_change.clone().map(move |cb| {
-                        let cb = cb.clone();
-                        let page_num = *page;
-                        move |_: MouseEvent| cb.emit(page_num)
-                    });
-
-                    let is_active = *page == current_page;
-                    let aria_current = is_active.then_some("page");
+                    let is_active = page == current_page;
 
                     html! {
                         <li class={classes!("pagination-item", if is_active { "active" } else { "" })}>
-                            <button
-                                type="button"
-                                aria-current={aria_current}
-                                {onclick}
-                            >
-                                { page.to_string() }
-                            </button>
+                            { controls.render_page(page, is_active) }
                         </li>
                     }
                 }) }
 
-                if show_first_last {
+                if props.show_first_last {
                     <li class="pagination-item">
-                        <button
-                            type="button"
-                            aria-label="Last page"
-                            disabled={current_page == total_pages}
-                            onclick={on_page_change.clone().map(move |cb| {
-                                let cb = cb.clone();
-                                move |_: MouseEvent| cb.emit(total_pages)
-                            })}
-                        >
-                            {"»"}
-                        </button>
+                        { controls.render(
+                            total_pages,
+                            Some("Last page"),
+                            current_page == total_pages,
+                            html! { {"»"} }
+                        ) }
                     </li>
                 }
 
-                if show_prev_next {
+                if props.show_prev_next {
                     <li class="pagination-item">
-                        <button
-                            type="button"
-                            aria-label="Next page"
-                            disabled={current_page >= total_pages}
-                            onclick={on_page_change.clone().map(move |cb| {
-                                let cb = cb.clone();
-                                move |_: MouseEvent| cb.emit(current_page + 1)
-                            })}
-                        >
-                            {"›"}
-                        </button>
+                        { controls.render(
+                            current_page + 1,
+                            Some("Next page"),
+                            current_page >= total_pages,
+                            html! { {"›"} }
+                        ) }
                     </li>
                 }
             </ul>
@@ -244,6 +239,117 @@ pub fn Pagination(props: &PaginationProps) -> Html {
     }
 }
 
+/// Per-render state shared by every pagination control.
+struct Controls {
+    on_page_change: Option<Callback<u32>>,
+    href_for:       Option<Callback<u32, AttrValue>>,
+    basename:       Option<String>,
+    aria_current:   AriaCurrent
+}
+
+impl Controls {
+    /// Renders a numbered page control; the current page carries
+    /// `aria-current` and stays focusable.
+    fn render_page(&self, page: u32, is_active: bool) -> Html {
+        let aria_current = self.aria_current.when(is_active);
+        if self.href_for.is_some() {
+            self.link(
+                page,
+                None,
+                false,
+                aria_current,
+                html! { { page.to_string() } }
+            )
+        } else {
+            html! {
+                <button type="button" aria-current={aria_current} onclick={self.emit_on_click(page)}>
+                    { page.to_string() }
+                </button>
+            }
+        }
+    }
+
+    /// Renders a prev/next or first/last control targeting `page`.
+    fn render(
+        &self,
+        page: u32,
+        aria_label: Option<&'static str>,
+        disabled: bool,
+        content: Html
+    ) -> Html {
+        if self.href_for.is_some() {
+            self.link(page, aria_label, disabled, None, content)
+        } else {
+            html! {
+                <button
+                    type="button"
+                    aria-label={aria_label}
+                    disabled={disabled}
+                    onclick={self.emit_on_click(page)}
+                >
+                    { content }
+                </button>
+            }
+        }
+    }
+
+    /// Renders an `<a>` control. A disabled control has no `href`, which
+    /// makes it inert, and is announced through `aria-disabled`.
+    fn link(
+        &self,
+        page: u32,
+        aria_label: Option<&'static str>,
+        disabled: bool,
+        aria_current: Option<&'static str>,
+        content: Html
+    ) -> Html {
+        if disabled {
+            return html! {
+                <a aria-label={aria_label} aria-disabled="true">{ content }</a>
+            };
+        }
+
+        let href = self.href(page);
+        let onclick = self.on_page_change.clone().map(|cb| {
+            Callback::from(move |event: MouseEvent| {
+                if is_modified_click(&event) {
+                    return;
+                }
+                event.prevent_default();
+                cb.emit(page);
+            })
+        });
+
+        html! {
+            <a href={href} aria-label={aria_label} aria-current={aria_current} {onclick}>
+                { content }
+            </a>
+        }
+    }
+
+    /// Returns the href of `page`, prefixing root-relative hrefs with the
+    /// router basename. Absolute, scheme-relative, and relative hrefs are
+    /// kept as given.
+    fn href(&self, page: u32) -> AttrValue {
+        let href = self
+            .href_for
+            .as_ref()
+            .map_or_else(AttrValue::default, |href_for| href_for.emit(page));
+        match &self.basename {
+            Some(base) if href.starts_with('/') && !href.starts_with("//") => {
+                AttrValue::from(with_basename(&href, base))
+            }
+            _ => href
+        }
+    }
+
+    fn emit_on_click(&self, page: u32) -> Option<Callback<MouseEvent>> {
+        self.on_page_change
+            .clone()
+            .map(|cb| Callback::from(move |_: MouseEvent| cb.emit(page)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,6 +363,8 @@ mod tests {
         assert!(!props.show_first_last);
         assert!(props.show_prev_next);
         assert!(props.on_page_change.is_none());
+        assert!(props.href_for.is_none());
+        assert_eq!(props.aria_current, AriaCurrent::Page);
     }
 
     #[test]
@@ -268,6 +376,8 @@ mod tests {
             show_first_last: true,
             show_prev_next:  false,
             on_page_change:  Some(Callback::from(|_: u32| {})),
+            href_for:        None,
+            aria_current:    AriaCurrent::Step,
             classes:         Classes::from("my-pagination")
         };
         assert_eq!(props.current_page, 5);
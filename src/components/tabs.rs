@@ -96,6 +96,9 @@ pub struct NavTabsProps {
 /// and last tab. Activation stays with the consumer via each tab's
 /// `onclick` (manual activation model).
 ///
+/// Focus is located and moved only in the keydown handler, so rendering
+/// reads nothing from the DOM and hydrates server markup unchanged.
+///
 /// # CSS Classes
 ///
 /// - `nav-tabs` - Always applied
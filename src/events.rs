@@ -0,0 +1,410 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! # Navigation events
+//!
+//! A [`NavEventsProvider`] placed inside the router publishes a
+//! [`NavEvent`] for every route change and every click on a
+//! [`NavLink`](crate::NavLink), so page views, click tracking, and logging
+//! are wired up in one place instead of a `use_effect_with(location, …)` in
+//! every page.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! #[component]
+//! fn Analytics() -> Html {
+//!     use_nav_event_listener::<Route, _>(|event| {
+//!         if let NavEvent::RouteChanged { to: Some(to), .. } = event {
+//!             track_page_view(&to.to_path());
+//!         }
+//!     });
+//!     html! {}
+//! }
+//!
+//! html! {
+//!     <BrowserRouter>
+//!         <NavEventsProvider<Route>>
+//!             <Analytics />
+//!             <Switch<Route> render={switch} />
+//!         </NavEventsProvider<Route>>
+//!     </BrowserRouter>
+//! }
+//! ```
+//!
+//! # Publishers
+//!
+//! | Event | Published by |
+//! |-------|--------------|
+//! | `RouteChanged` | The provider, on every location change including the first render |
+//! | `NotFound` | The provider, after `RouteChanged`, when no route or the `#[not_found]` route matches |
+//! | `LinkClicked` | [`NavLink`](crate::NavLink), before it navigates |
+//! | `NavigationBlocked` | Whoever refuses a navigation, through [`NavEvents::emit`] |
+//!
+//! The kind of a route change comes from the entry id yew-router stores in
+//! the history state: an id seen before is a [`NavigationDirection::Pop`];
+//! a new one is the push or replace [`NavLink`](crate::NavLink) or
+//! [`Navigation`](crate::Navigation) announced through
+//! [`NavEvents::expect`], and a push otherwise. Locations without an id
+//! (the initial page load, pushed outside the router) take the announced
+//! kind, so going back to the first entry is a pop. An announcement is
+//! dropped by the next change whether it applied or not, and changes that
+//! keep the path and query, like fragment links, are not published.
+//!
+//! A [`NavigationHistoryProvider`](crate::NavigationHistoryProvider) nested
+//! with a [`NavEventsProvider`], in either order, shares this
+//! classification, so its entries record the same kind as the events.
+
+mod classifier;
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    rc::Rc
+};
+
+pub(crate) use classifier::{Classifier, use_classifier};
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// How a route was reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NavigationDirection {
+    /// A new entry was pushed (a link, `Navigator::push`).
+    Push,
+    /// The current entry was replaced.
+    Replace,
+    /// The user moved back or forward to an existing entry.
+    Pop
+}
+
+/// An event published on [`NavEvents`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum NavEvent<R> {
+    /// The location changed. `from` is `None` on the first render.
+    RouteChanged {
+        /// The route before the change.
+        from: Option<R>,
+        /// The route after the change, `None` when no route matches.
+        to:   Option<R>,
+        /// How the new location was reached.
+        kind: NavigationDirection
+    },
+    /// A [`NavLink`](crate::NavLink) was clicked.
+    LinkClicked {
+        /// The link's target route.
+        target:   R,
+        /// `true` when a modifier key was held, so the browser handles the
+        /// click (e.g. opens a new tab) instead of the router.
+        modifier: bool
+    },
+    /// A navigation to `target` was refused.
+    NavigationBlocked {
+        /// The refused target route.
+        target: R
+    },
+    /// The location matches no route, or the `#[not_found]` one.
+    NotFound {
+        /// Browser path of the location, including the router basename.
+        path: String
+    }
+}
+
+type Listener<R> = Rc<dyn Fn(&NavEvent<R>)>;
+
+struct Bus<R> {
+    listeners:  RefCell<Vec<(usize, Listener<R>)>>,
+    next_id:    Cell<usize>,
+    classifier: Classifier
+}
+
+/// Handle to the event bus of a [`NavEventsProvider`], compared by
+/// identity.
+pub struct NavEvents<R> {
+    bus: Rc<Bus<R>>
+}
+
+impl<R> NavEvents<R> {
+    /// Creates a bus without listeners.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_classifier(Classifier::default())
+    }
+
+    /// Creates a bus that announces and classifies route changes through
+    /// `classifier`.
+    fn with_classifier(classifier: Classifier) -> Self {
+        Self {
+            bus: Rc::new(Bus {
+                listeners: RefCell::new(Vec::new()),
+                next_id: Cell::new(0),
+                classifier
+            })
+        }
+    }
+
+    /// Calls every listener with `event`, in subscription order.
+    ///
+    /// Listeners may subscribe or unsubscribe while being called; the
+    /// change applies from the next event on.
+    pub fn emit(&self, event: &NavEvent<R>) {
+        let listeners: Vec<Listener<R>> = self
+            .bus
+            .listeners
+            .borrow()
+            .iter()
+            .map(|(_, listener)| Rc::clone(listener))
+            .collect();
+        for listener in listeners {
+            listener(event);
+        }
+    }
+
+    /// Adds `listener` and returns a closure that removes it again.
+    pub fn subscribe<F>(&self, listener: F) -> impl FnOnce() + use<R, F>
+    where
+        R: 'static,
+        F: Fn(&NavEvent<R>) + 'static
+    {
+        let id = self.bus.next_id.get();
+        self.bus.next_id.set(id + 1);
+        self.bus
+            .listeners
+            .borrow_mut()
+            .push((id, Rc::new(listener)));
+        let bus = Rc::clone(&self.bus);
+        move || bus.listeners.borrow_mut().retain(|(other, _)| *other != id)
+    }
+
+    /// Announces the kind of the next route change, for navigations that
+    /// create a new history entry.
+    pub fn expect(&self, kind: NavigationDirection) {
+        self.bus.classifier.expect(kind);
+    }
+}
+
+impl<R> Default for NavEvents<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> Clone for NavEvents<R> {
+    fn clone(&self) -> Self {
+        Self {
+            bus: Rc::clone(&self.bus)
+        }
+    }
+}
+
+impl<R> PartialEq for NavEvents<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.bus, &other.bus)
+    }
+}
+
+impl<R> fmt::Debug for NavEvents<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NavEvents")
+            .field("listeners", &self.bus.listeners.borrow().len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Properties for the [`NavEventsProvider`] component.
+///
+/// | Prop | Type | Default | Description |
+/// |------|------|---------|-------------|
+/// | `children` | `Children` | — | The routed content |
+#[derive(Properties, Clone, PartialEq, Debug, Default)]
+pub struct NavEventsProviderProps {
+    /// The routed content.
+    #[prop_or_default]
+    pub children: Children
+}
+
+/// Provides a [`NavEvents`] bus for routes `R` and publishes route changes
+/// on it.
+///
+/// Must be rendered inside a router.
+#[function_component]
+pub fn NavEventsProvider<R>(props: &NavEventsProviderProps) -> Html
+where
+    R: Routable + Clone + PartialEq + 'static
+{
+    let classifier = use_classifier();
+    let events = use_memo(classifier.clone(), |classifier| {
+        NavEvents::<R>::with_classifier(classifier.clone())
+    });
+    let route = use_route::<R>();
+    let location = use_location();
+    let previous = use_mut_ref(|| None::<R>);
+
+    {
+        let events = (*events).clone();
+        let key = location
+            .as_ref()
+            .map(|l| (l.id(), l.path().to_string(), l.query_str().to_string()));
+        use_effect_with(key, move |key| {
+            if let Some((id, path, query)) = key
+                && let Some(kind) = events.bus.classifier.observe(*id, path, query)
+            {
+                let from = previous.replace(route.clone());
+                let is_not_found = route
+                    .as_ref()
+                    .is_none_or(|route| R::not_found_route().as_ref() == Some(route));
+                events.emit(&NavEvent::RouteChanged {
+                    from,
+                    to: route,
+                    kind
+                });
+                if is_not_found {
+                    events.emit(&NavEvent::NotFound {
+                        path: path.clone()
+                    });
+                }
+            }
+        });
+    }
+
+    html! {
+        <ContextProvider<Classifier> context={classifier}>
+            <ContextProvider<NavEvents<R>> context={(*events).clone()}>
+                { for props.children.iter() }
+            </ContextProvider<NavEvents<R>>>
+        </ContextProvider<Classifier>>
+    }
+}
+
+/// Returns the [`NavEvents`] bus of the enclosing [`NavEventsProvider`],
+/// e.g. to publish [`NavEvent::NavigationBlocked`] from a guard.
+#[hook]
+pub fn use_nav_events<R>() -> Option<NavEvents<R>>
+where
+    R: Routable + 'static
+{
+    use_context::<NavEvents<R>>()
+}
+
+/// Calls `listener` for every event of the enclosing [`NavEventsProvider`].
+///
+/// The listener is subscribed once and always sees the closure of the
+/// latest render. Without a provider it is never called.
+///
+/// ```rust,ignore
+/// use_nav_event_listener::<Route, _>(move |event| log::info!("{event:?}"));
+/// ```
+#[hook]
+pub fn use_nav_event_listener<R, F>(listener: F)
+where
+    R: Routable + 'static,
+    F: Fn(&NavEvent<R>) + 'static
+{
+    let events = use_nav_events::<R>();
+    let latest = use_mut_ref(|| None::<Listener<R>>);
+    *latest.borrow_mut() = Some(Rc::new(listener));
+
+    use_effect_with(events, move |events| {
+        let unsubscribe = events.as_ref().map(|events| {
+            events.subscribe(move |event| {
+                let listener = latest.borrow().clone();
+                if let Some(listener) = listener {
+                    listener(event);
+                }
+            })
+        });
+        move || {
+            if let Some(unsubscribe) = unsubscribe {
+                unsubscribe();
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq, Debug, Routable)]
+    enum TestRoute {
+        #[at("/")]
+        Home,
+        #[at("/docs")]
+        Docs
+    }
+
+    fn recorder(events: &NavEvents<TestRoute>) -> (Rc<RefCell<Vec<String>>>, impl FnOnce()) {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let unsubscribe = events.subscribe({
+            let log = Rc::clone(&log);
+            move |event| log.borrow_mut().push(format!("{event:?}"))
+        });
+        (log, unsubscribe)
+    }
+
+    #[test]
+    fn emit_reaches_every_listener_until_unsubscribed() {
+        let events = NavEvents::new();
+        let (first, unsubscribe_first) = recorder(&events);
+        let (second, _unsubscribe_second) = recorder(&events);
+        let click = NavEvent::LinkClicked {
+            target:   TestRoute::Docs,
+            modifier: false
+        };
+
+        events.emit(&click);
+        unsubscribe_first();
+        events.emit(&NavEvent::NotFound {
+            path: "/x".to_string()
+        });
+
+        assert_eq!(first.borrow().len(), 1);
+        assert_eq!(second.borrow().len(), 2);
+        assert_eq!(format!("{events:?}"), "NavEvents { listeners: 1, .. }");
+    }
+
+    #[test]
+    fn listener_may_unsubscribe_during_emit() {
+        let events = NavEvents::<TestRoute>::new();
+        let unsubscribe = Rc::new(RefCell::new(None::<Box<dyn FnOnce()>>));
+        let calls = Rc::new(Cell::new(0));
+        let handle = events.subscribe({
+            let unsubscribe = Rc::clone(&unsubscribe);
+            let calls = Rc::clone(&calls);
+            move |_| {
+                calls.set(calls.get() + 1);
+                if let Some(unsubscribe) = unsubscribe.borrow_mut().take() {
+                    unsubscribe();
+                }
+            }
+        });
+        *unsubscribe.borrow_mut() = Some(Box::new(handle));
+
+        let blocked = NavEvent::NavigationBlocked {
+            target: TestRoute::Home
+        };
+        events.emit(&blocked);
+        events.emit(&blocked);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn clones_share_the_bus() {
+        let events = NavEvents::<TestRoute>::new();
+        assert_eq!(events.clone(), events);
+        assert_ne!(NavEvents::<TestRoute>::default(), events);
+    }
+
+    #[test]
+    fn nav_events_provider_props_default() {
+        let props = NavEventsProviderProps::default();
+        assert!(props.children.is_empty());
+    }
+
+    #[test]
+    fn nav_event_hooks_are_callable() {
+        let _ = use_nav_events::<TestRoute>();
+        let _ = use_nav_event_listener::<TestRoute, _>(|_| {});
+    }
+}
@@ -0,0 +1,257 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Push, replace, and pop classification of location changes, shared by
+//! [`NavEventsProvider`](super::NavEventsProvider) and
+//! [`NavigationHistoryProvider`](crate::NavigationHistoryProvider) so both
+//! report the same kind for the same change.
+//!
+//! The first provider rendered inside the router creates a [`Classifier`]
+//! and provides it as context; a provider nested in it, and every
+//! [`Navigation`](crate::Navigation) and [`NavLink`](crate::NavLink) below,
+//! use that one. Each provider observes every location change, and the
+//! classifier answers the second observation of a change from the first.
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    fmt,
+    rc::Rc
+};
+
+use yew::prelude::*;
+
+use super::NavigationDirection;
+
+/// Most history entry ids remembered for telling pops from pushes.
+const SEEN_CAPACITY: usize = 256;
+
+/// The last location change a [`Classifier`] observed.
+struct Observation {
+    id:    Option<u32>,
+    path:  String,
+    query: String,
+    kind:  Option<NavigationDirection>
+}
+
+#[derive(Default)]
+struct State {
+    expected: Cell<Option<NavigationDirection>>,
+    seen:     RefCell<VecDeque<u32>>,
+    last:     RefCell<Option<Observation>>
+}
+
+/// Handle to the classification state of a navigation provider, compared by
+/// identity.
+#[derive(Clone, Default)]
+pub struct Classifier {
+    state: Rc<State>
+}
+
+impl Classifier {
+    /// Announces the kind of the next location change.
+    pub fn expect(&self, kind: NavigationDirection) {
+        self.state.expected.set(Some(kind));
+    }
+
+    /// Returns how the location with the history entry `id`, `path`, and
+    /// `query` was reached, or `None` when it keeps the path and query of
+    /// the previous one (a fragment link) and is no new visit.
+    ///
+    /// An id seen before is a pop. A new id takes the announced kind, except
+    /// a pop: an announced pop that moved nothing (`go(0)`, `back()` on the
+    /// first entry) must not label a new entry. Locations without an id (the
+    /// initial page load, entries pushed outside the router) take the
+    /// announced kind, so going back to the first entry is a pop. Every
+    /// change consumes the announcement; observing the same change again
+    /// returns the first answer.
+    pub fn observe(
+        &self,
+        id: Option<u32>,
+        path: &str,
+        query: &str
+    ) -> Option<NavigationDirection> {
+        let mut last = self.state.last.borrow_mut();
+        if let Some(observed) = last.as_ref()
+            && observed.id == id
+            && observed.path == path
+            && observed.query == query
+        {
+            return observed.kind;
+        }
+        let expected = self.state.expected.take();
+        let kind = self.classify(id, expected);
+        let visits = last
+            .as_ref()
+            .is_none_or(|observed| observed.path != path || observed.query != query);
+        let kind = visits.then_some(kind);
+        *last = Some(Observation {
+            id,
+            path: path.to_string(),
+            query: query.to_string(),
+            kind
+        });
+        kind
+    }
+
+    fn classify(
+        &self,
+        id: Option<u32>,
+        expected: Option<NavigationDirection>
+    ) -> NavigationDirection {
+        let Some(id) = id else {
+            return expected.unwrap_or(NavigationDirection::Push);
+        };
+        let mut seen = self.state.seen.borrow_mut();
+        if seen.contains(&id) {
+            return NavigationDirection::Pop;
+        }
+        if seen.len() == SEEN_CAPACITY {
+            seen.pop_front();
+        }
+        seen.push_back(id);
+        expected
+            .filter(|kind| *kind != NavigationDirection::Pop)
+            .unwrap_or(NavigationDirection::Push)
+    }
+}
+
+impl PartialEq for Classifier {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl fmt::Debug for Classifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Classifier")
+            .field("expected", &self.state.expected.get())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the [`Classifier`] of an enclosing navigation provider, or a new
+/// one owned by the calling provider.
+#[hook]
+pub fn use_classifier() -> Classifier {
+    let outer = use_context::<Classifier>();
+    let own = use_memo((), |()| Classifier::default());
+    outer.unwrap_or_else(|| (*own).clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observe_classifies_route_changes() {
+        let classifier = Classifier::default();
+        assert_eq!(
+            classifier.observe(None, "/", ""),
+            Some(NavigationDirection::Push)
+        );
+        classifier.expect(NavigationDirection::Replace);
+        assert_eq!(
+            classifier.observe(Some(1), "/a", ""),
+            Some(NavigationDirection::Replace)
+        );
+        assert_eq!(
+            classifier.observe(Some(2), "/b", ""),
+            Some(NavigationDirection::Push)
+        );
+        classifier.expect(NavigationDirection::Pop);
+        assert_eq!(
+            classifier.observe(Some(1), "/a", ""),
+            Some(NavigationDirection::Pop)
+        );
+        assert_eq!(
+            classifier.observe(Some(3), "/c", ""),
+            Some(NavigationDirection::Push),
+            "an expectation is consumed by one change"
+        );
+    }
+
+    #[test]
+    fn the_same_change_gets_the_same_answer() {
+        let classifier = Classifier::default();
+        classifier.observe(None, "/", "");
+        classifier.observe(Some(1), "/search", "q=a");
+        classifier.expect(NavigationDirection::Pop);
+        let first = classifier.observe(None, "/", "");
+        let second = classifier.observe(None, "/", "");
+        assert_eq!(first, Some(NavigationDirection::Pop));
+        assert_eq!(second, first, "a second provider sees the same kind");
+    }
+
+    #[test]
+    fn locations_without_id_are_never_seen() {
+        let classifier = Classifier::default();
+        assert_eq!(
+            classifier.observe(None, "/", ""),
+            Some(NavigationDirection::Push)
+        );
+        assert_eq!(
+            classifier.observe(None, "/a", ""),
+            Some(NavigationDirection::Push)
+        );
+        classifier.expect(NavigationDirection::Pop);
+        assert_eq!(
+            classifier.observe(None, "/", ""),
+            Some(NavigationDirection::Pop),
+            "going back to the initial entry"
+        );
+    }
+
+    #[test]
+    fn unfulfilled_pop_does_not_label_a_new_entry() {
+        let classifier = Classifier::default();
+        classifier.expect(NavigationDirection::Pop);
+        assert_eq!(
+            classifier.observe(Some(1), "/a", ""),
+            Some(NavigationDirection::Push)
+        );
+    }
+
+    #[test]
+    fn same_path_and_query_is_no_visit() {
+        let classifier = Classifier::default();
+        classifier.observe(Some(1), "/docs", "page=2");
+        classifier.expect(NavigationDirection::Replace);
+        assert_eq!(classifier.observe(Some(2), "/docs", "page=2"), None);
+        assert_eq!(
+            classifier.observe(Some(3), "/docs", "page=3"),
+            Some(NavigationDirection::Push),
+            "the skipped change consumed the announcement"
+        );
+    }
+
+    #[test]
+    fn seen_ids_are_capped() {
+        let classifier = Classifier::default();
+        for id in 0..=u32::try_from(SEEN_CAPACITY).unwrap() {
+            classifier.observe(Some(id), &format!("/{id}"), "");
+        }
+        assert_eq!(classifier.state.seen.borrow().len(), SEEN_CAPACITY);
+        assert_eq!(
+            classifier.observe(Some(0), "/0", ""),
+            Some(NavigationDirection::Push)
+        );
+        assert_eq!(
+            classifier.observe(Some(2), "/2", ""),
+            Some(NavigationDirection::Pop)
+        );
+    }
+
+    #[test]
+    fn clones_share_the_state() {
+        let classifier = Classifier::default();
+        assert_eq!(classifier.clone(), classifier);
+        assert_ne!(Classifier::default(), classifier);
+        let clone = classifier.clone();
+        clone.expect(NavigationDirection::Replace);
+        assert_eq!(
+            format!("{classifier:?}"),
+            "Classifier { expected: Some(Replace), .. }"
+        );
+    }
+}
@@ -9,8 +9,15 @@
 mod navigation;
 mod route_info;
 
-pub use navigation::{Navigation, use_navigation, use_query_params};
+pub use navigation::{
+    HistoryEntry, Navigation, NavigationHistoryProvider, NavigationHistoryProviderProps,
+    use_basename, use_history_stack, use_navigation, use_previous_route, use_query_params
+};
 pub use route_info::{
-    BreadcrumbItem, BreadcrumbLabelProvider, BreadcrumbLabelProviderContext, use_breadcrumbs,
-    use_is_active, use_is_exact_active, use_is_partial_active, use_route_info
+    AsyncBreadcrumbLabelProvider, BreadcrumbConfig, BreadcrumbContext, BreadcrumbItem,
+    BreadcrumbLabelProvider, BreadcrumbLabelProviderContext, BreadcrumbRule, BreadcrumbTree,
+    FnLabelProvider, LabelFuture, MapLabelProvider, RouteInfo, RouteLabelProvider,
+    RouteLabelProviderContext, RouteParent, route_param, use_active_index, use_active_route_among,
+    use_breadcrumbs, use_is_active, use_is_exact_active, use_is_partial_active,
+    use_route_breadcrumbs, use_route_info, use_route_param
 };
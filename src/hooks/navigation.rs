@@ -1,8 +1,15 @@
 // SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+pub mod basename;
+pub mod history;
 pub mod query_params;
 pub mod use_navigation;
 
+pub use basename::use_basename;
+pub use history::{
+    HistoryEntry, NavigationHistoryProvider, NavigationHistoryProviderProps, use_history_stack,
+    use_previous_route
+};
 pub use query_params::use_query_params;
 pub use use_navigation::{Navigation, use_navigation};
@@ -0,0 +1,52 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Router basename hook.
+//!
+//! [`use_basename`] exposes the `basename` configured on the enclosing
+//! `BrowserRouter`, so hand-built hrefs can be prefixed with
+//! [`with_basename`](crate::with_basename) and browser paths reduced to
+//! route paths with [`strip_basename`](crate::strip_basename).
+
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// Returns the basename of `navigator`, treating an empty one as unset.
+fn basename_of(navigator: Option<&Navigator>) -> Option<&str> {
+    navigator
+        .and_then(Navigator::basename)
+        .filter(|base| !base.is_empty())
+}
+
+/// Returns the router basename without its trailing `/` (e.g. `/app/v2`),
+/// or `None` when no basename is configured or no router is in scope.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use yew::prelude::*;
+/// use yew_nav_link::{use_basename, with_basename};
+///
+/// #[component]
+/// fn Download() -> Html {
+///     let basename = use_basename().unwrap_or_default();
+///     let href = with_basename("/files/report.pdf", &basename);
+///
+///     html! { <a href={href} download="">{ "Report" }</a> }
+/// }
+/// ```
+#[hook]
+pub fn use_basename() -> Option<String> {
+    let navigator = use_navigator();
+    basename_of(navigator.as_ref()).map(ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basename_of_without_navigator_is_none() {
+        assert_eq!(basename_of(None), None);
+    }
+}
@@ -0,0 +1,473 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! In-app navigation history.
+//!
+//! The browser does not tell a page where the user came from, and
+//! `history.length` counts entries of other sites too. A
+//! [`NavigationHistoryProvider`] placed inside the router records every
+//! visited route of the session in a bounded stack, which
+//! [`use_previous_route`] and [`use_history_stack`] read and which
+//! [`Navigation::go_back_or`](crate::Navigation::go_back_or) consults before
+//! leaving the app.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! html! {
+//!     <BrowserRouter>
+//!         <NavigationHistoryProvider<Route> capacity={20}>
+//!             <Switch<Route> render={switch} />
+//!         </NavigationHistoryProvider<Route>>
+//!     </BrowserRouter>
+//! }
+//!
+//! #[component]
+//! fn ResultPage() -> Html {
+//!     let nav = use_navigation::<Route>();
+//!     let back = nav.go_back_or(Route::Search).reform(|_: MouseEvent| ());
+//!     html! { <button onclick={back}>{ "Back to results" }</button> }
+//! }
+//! ```
+//!
+//! # How It Works
+//!
+//! Location changes are classified like the route changes of a
+//! [`NavEventsProvider`](crate::NavEventsProvider), and by the same
+//! classifier when the two providers are nested. Every history entry
+//! created through yew-router carries an id: an id seen before means the
+//! user moved through history ([`NavigationDirection::Pop`]) and the entry
+//! with that id becomes the current one. A new id is a push, unless
+//! [`Navigation::replace_callback`](crate::Navigation::replace_callback)
+//! announced a replace. The initial page load has no id; going back to it
+//! through [`Navigation`](crate::Navigation) is a pop to the nearest earlier
+//! entry without an id and with the same route. Pushing after going back
+//! drops the forward entries, as the browser does.
+
+use std::rc::Rc;
+
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::events::{Classifier, NavigationDirection, use_classifier};
+
+/// One visited route recorded by a [`NavigationHistoryProvider`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry<R> {
+    /// The visited route.
+    pub route:     R,
+    /// How the entry was reached most recently.
+    pub direction: NavigationDirection,
+    /// When the entry was reached most recently, in milliseconds since the
+    /// Unix epoch.
+    pub timestamp: f64,
+    id:            Option<u32>
+}
+
+/// Bounded stack of visited routes, mirroring the session history.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NavigationHistory<R> {
+    entries:  Vec<HistoryEntry<R>>,
+    cursor:   usize,
+    capacity: usize
+}
+
+/// A location change seen by the provider.
+pub struct Visit<R> {
+    route:     R,
+    id:        Option<u32>,
+    kind:      NavigationDirection,
+    timestamp: f64
+}
+
+impl<R> NavigationHistory<R> {
+    /// Creates an empty history keeping at most `capacity` entries.
+    const fn new(capacity: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            cursor: 0,
+            capacity
+        }
+    }
+
+    /// Returns the entries from the oldest one to the current one.
+    fn back_stack(&self) -> &[HistoryEntry<R>] {
+        self.entries.get(..=self.cursor).unwrap_or_default()
+    }
+
+    /// Returns the entry before the current one.
+    fn previous(&self) -> Option<&HistoryEntry<R>> {
+        self.cursor
+            .checked_sub(1)
+            .and_then(|index| self.entries.get(index))
+    }
+}
+
+impl<R> NavigationHistory<R>
+where
+    R: PartialEq
+{
+    /// Returns the index of the entry a pop to `visit` lands on.
+    fn popped_to(&self, visit: &Visit<R>) -> Option<usize> {
+        visit.id.map_or_else(
+            // Locations without router state (the initial page load) all
+            // have no id, so the route tells them apart.
+            || {
+                self.entries[..self.cursor]
+                    .iter()
+                    .rposition(|e| e.id.is_none() && e.route == visit.route)
+            },
+            |id| self.entries.iter().position(|e| e.id == Some(id))
+        )
+    }
+}
+
+impl<R> Reducible for NavigationHistory<R>
+where
+    R: Clone + PartialEq
+{
+    type Action = Visit<R>;
+
+    fn reduce(self: Rc<Self>, visit: Self::Action) -> Rc<Self> {
+        let mut next = (*self).clone();
+        let popped_to = match visit.kind {
+            NavigationDirection::Pop => next.popped_to(&visit),
+            _ => None
+        };
+        let entry = HistoryEntry {
+            route:     visit.route,
+            direction: visit.kind,
+            timestamp: visit.timestamp,
+            id:        visit.id
+        };
+        if let Some(index) = popped_to {
+            next.cursor = index;
+            next.entries[index] = entry;
+        } else if visit.kind == NavigationDirection::Replace && !next.entries.is_empty() {
+            next.entries[next.cursor] = entry;
+        } else {
+            next.entries.truncate(next.cursor + 1);
+            next.entries.push(entry);
+            let overflow = next.entries.len().saturating_sub(next.capacity.max(1));
+            next.entries.drain(..overflow);
+            next.cursor = next.entries.len() - 1;
+        }
+        Rc::new(next)
+    }
+}
+
+/// Context provided by [`NavigationHistoryProvider`].
+#[derive(Clone, PartialEq)]
+pub struct NavigationHistoryContext<R>
+where
+    R: Clone + PartialEq + 'static
+{
+    history: UseReducerHandle<NavigationHistory<R>>
+}
+
+/// Returns whether the enclosing [`NavigationHistoryProvider`] for routes
+/// `R` has recorded an entry before the current one, or `None` without a
+/// provider.
+#[hook]
+pub fn use_can_go_back<R>() -> Option<bool>
+where
+    R: Routable + 'static
+{
+    use_context::<NavigationHistoryContext<R>>()
+        .map(|context| context.history.previous().is_some())
+}
+
+/// Properties for the [`NavigationHistoryProvider`] component.
+///
+/// | Prop | Type | Default | Description |
+/// |------|------|---------|-------------|
+/// | `capacity` | `usize` | `50` | Most entries kept; the oldest are dropped first |
+/// | `children` | `Children` | — | The routed content |
+#[derive(Properties, Clone, PartialEq, Debug)]
+pub struct NavigationHistoryProviderProps {
+    /// Maximum number of recorded entries, at least one.
+    #[prop_or(50)]
+    pub capacity: usize,
+
+    /// The routed content.
+    #[prop_or_default]
+    pub children: Children
+}
+
+impl Default for NavigationHistoryProviderProps {
+    fn default() -> Self {
+        Self {
+            capacity: 50,
+            children: Children::default()
+        }
+    }
+}
+
+/// Records the routes of `R` visited in this session for
+/// [`use_previous_route`], [`use_history_stack`], and
+/// [`Navigation::go_back_or`](crate::Navigation::go_back_or).
+///
+/// Must be rendered inside a router. Locations that match no route of `R`
+/// are not recorded.
+#[function_component]
+pub fn NavigationHistoryProvider<R>(props: &NavigationHistoryProviderProps) -> Html
+where
+    R: Routable + Clone + PartialEq + 'static
+{
+    let history = use_reducer({
+        let capacity = props.capacity;
+        move || NavigationHistory::<R>::new(capacity)
+    });
+    let classifier = use_classifier();
+    let route = use_route::<R>();
+    let location = use_location();
+
+    {
+        let dispatcher = history.dispatcher();
+        let classifier = classifier.clone();
+        let key = location
+            .as_ref()
+            .map(|l| (l.id(), l.path().to_string(), l.query_str().to_string()));
+        use_effect_with(key, move |key| {
+            if let Some((id, path, query)) = key
+                && let Some(kind) = classifier.observe(*id, path, query)
+                && let Some(route) = route
+            {
+                dispatcher.dispatch(Visit {
+                    route,
+                    id: *id,
+                    kind,
+                    timestamp: web_sys::js_sys::Date::now()
+                });
+            }
+        });
+    }
+
+    let context = NavigationHistoryContext {
+        history
+    };
+    html! {
+        <ContextProvider<Classifier> context={classifier}>
+            <ContextProvider<NavigationHistoryContext<R>> {context}>
+                { for props.children.iter() }
+            </ContextProvider<NavigationHistoryContext<R>>>
+        </ContextProvider<Classifier>>
+    }
+}
+
+/// Returns the route before the current one in the recorded history, i.e.
+/// where the browser's back button leads, or `None` without a
+/// [`NavigationHistoryProvider`] or an earlier in-app entry.
+///
+/// ```rust,ignore
+/// let came_from_search = use_previous_route::<Route>() == Some(Route::Search);
+/// ```
+#[hook]
+pub fn use_previous_route<R>() -> Option<R>
+where
+    R: Routable + 'static
+{
+    use_context::<NavigationHistoryContext<R>>()
+        .and_then(|context| context.history.previous().map(|entry| entry.route.clone()))
+}
+
+/// Returns the recorded entries from the oldest one to the current one, or
+/// an empty list without a [`NavigationHistoryProvider`].
+///
+/// Entries the user went back from are not included.
+#[hook]
+pub fn use_history_stack<R>() -> Vec<HistoryEntry<R>>
+where
+    R: Routable + 'static
+{
+    use_context::<NavigationHistoryContext<R>>()
+        .map(|context| context.history.back_stack().to_vec())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq, Debug, Routable)]
+    enum TestRoute {
+        #[at("/")]
+        Home,
+        #[at("/search")]
+        Search,
+        #[at("/result")]
+        Result
+    }
+
+    /// A provider's view of the session: every location change goes through
+    /// the classifier, and the history records the kind it reports.
+    struct Session {
+        classifier: Classifier,
+        history:    Rc<NavigationHistory<TestRoute>>
+    }
+
+    impl Session {
+        fn new(capacity: usize) -> Self {
+            Self {
+                classifier: Classifier::default(),
+                history:    Rc::new(NavigationHistory::new(capacity))
+            }
+        }
+
+        fn visit(&mut self, route: TestRoute, id: Option<u32>) -> Option<NavigationDirection> {
+            let kind = self.classifier.observe(id, &route.to_path(), "")?;
+            self.history = Rc::clone(&self.history).reduce(Visit {
+                route,
+                id,
+                kind,
+                timestamp: id.map_or(0.0, f64::from)
+            });
+            Some(kind)
+        }
+
+        fn routes(&self) -> Vec<TestRoute> {
+            self.history
+                .back_stack()
+                .iter()
+                .map(|entry| entry.route.clone())
+                .collect()
+        }
+
+        fn previous(&self) -> Option<&TestRoute> {
+            self.history.previous().map(|entry| &entry.route)
+        }
+    }
+
+    fn browsed() -> Session {
+        let mut session = Session::new(10);
+        session.visit(TestRoute::Home, Some(1));
+        session.visit(TestRoute::Search, Some(2));
+        session.visit(TestRoute::Result, Some(3));
+        session
+    }
+
+    #[test]
+    fn pushes_grow_the_stack() {
+        let session = browsed();
+        assert_eq!(
+            session.routes(),
+            [TestRoute::Home, TestRoute::Search, TestRoute::Result]
+        );
+        assert_eq!(session.previous(), Some(&TestRoute::Search));
+        let entry = &session.history.entries[2];
+        assert_eq!(entry.direction, NavigationDirection::Push);
+        assert!((entry.timestamp - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn known_id_is_a_pop() {
+        let mut session = browsed();
+        assert_eq!(
+            session.visit(TestRoute::Search, Some(2)),
+            Some(NavigationDirection::Pop)
+        );
+        assert_eq!(session.routes(), [TestRoute::Home, TestRoute::Search]);
+        assert_eq!(session.history.entries.len(), 3, "forward entry is kept");
+        assert_eq!(
+            session.history.entries[1].direction,
+            NavigationDirection::Pop
+        );
+
+        session.visit(TestRoute::Result, Some(3));
+        assert_eq!(
+            session.history.cursor, 2,
+            "forward returns to the kept entry"
+        );
+    }
+
+    #[test]
+    fn going_back_to_the_initial_entry_is_a_pop() {
+        let mut session = Session::new(10);
+        session.visit(TestRoute::Home, None);
+        session.visit(TestRoute::Search, Some(1));
+        assert!(session.previous().is_some());
+
+        session.classifier.expect(NavigationDirection::Pop);
+        // A nested events provider observes the change first.
+        let published = session.classifier.observe(None, "/", "");
+        let recorded = session.visit(TestRoute::Home, None);
+        assert_eq!(published, Some(NavigationDirection::Pop));
+        assert_eq!(recorded, published, "both providers agree");
+
+        assert_eq!(session.routes(), [TestRoute::Home]);
+        assert_eq!(session.previous(), None, "nothing left to go back to");
+        assert_eq!(session.history.entries.len(), 2, "forward entry is kept");
+        assert_eq!(
+            session.history.entries[0].direction,
+            NavigationDirection::Pop
+        );
+    }
+
+    #[test]
+    fn push_after_back_drops_forward_entries() {
+        let mut session = browsed();
+        session.visit(TestRoute::Home, Some(1));
+        session.visit(TestRoute::Result, Some(4));
+        assert_eq!(session.routes(), [TestRoute::Home, TestRoute::Result]);
+        assert_eq!(session.history.entries.len(), 2);
+    }
+
+    #[test]
+    fn replace_overwrites_the_current_entry() {
+        let mut session = browsed();
+        session.classifier.expect(NavigationDirection::Replace);
+        session.visit(TestRoute::Home, Some(4));
+        assert_eq!(
+            session.routes(),
+            [TestRoute::Home, TestRoute::Search, TestRoute::Home]
+        );
+        assert_eq!(
+            session.history.entries[2].direction,
+            NavigationDirection::Replace
+        );
+    }
+
+    #[test]
+    fn replace_on_empty_history_adds_the_first_entry() {
+        let mut session = Session::new(10);
+        session.classifier.expect(NavigationDirection::Replace);
+        session.visit(TestRoute::Home, Some(1));
+        assert_eq!(session.routes(), [TestRoute::Home]);
+        assert_eq!(
+            session.history.entries[0].direction,
+            NavigationDirection::Replace
+        );
+    }
+
+    #[test]
+    fn capacity_drops_the_oldest_entries() {
+        let mut session = Session::new(2);
+        session.visit(TestRoute::Home, Some(1));
+        session.visit(TestRoute::Search, Some(2));
+        session.visit(TestRoute::Result, Some(3));
+        assert_eq!(session.routes(), [TestRoute::Search, TestRoute::Result]);
+        assert_eq!(session.history.cursor, 1);
+    }
+
+    #[test]
+    fn empty_history_has_no_previous_route() {
+        let mut session = Session::new(10);
+        assert!(session.history.back_stack().is_empty());
+        assert_eq!(session.previous(), None);
+        session.visit(TestRoute::Home, Some(1));
+        assert_eq!(session.previous(), None);
+    }
+
+    #[test]
+    fn navigation_history_provider_props_default() {
+        let props = NavigationHistoryProviderProps::default();
+        assert_eq!(props.capacity, 50);
+    }
+
+    #[test]
+    fn history_hooks_are_callable() {
+        let _ = use_previous_route::<TestRoute>();
+        let _ = use_history_stack::<TestRoute>();
+        let _ = use_can_go_back::<TestRoute>();
+    }
+}
@@ -15,6 +15,12 @@ use std::marker::PhantomData;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use super::history::use_can_go_back;
+use crate::{
+    events::{Classifier, NavigationDirection},
+    utils::{QueryParams, with_basename}
+};
+
 /// Handle for programmatic route manipulation, created by [`use_navigation`].
 ///
 /// `go_back` and `go_forward` are ready-made callbacks. `push_callback`,
@@ -27,6 +33,8 @@ where
     R: Routable + Clone + 'static
 {
     navigator:      Option<Navigator>,
+    can_go_back:    bool,
+    classifier:     Option<Classifier>,
     /// Callback to navigate back in history.
     pub go_back:    Callback<()>,
     /// Callback to navigate forward in history.
@@ -44,8 +52,10 @@ where
     /// basename is prepended.
     pub fn push_callback(&self, route: R) -> Callback<()> {
         let navigator = self.navigator.clone();
+        let classifier = self.classifier.clone();
         Callback::from(move |()| {
             if let Some(navigator) = &navigator {
+                expect(classifier.as_ref(), NavigationDirection::Push);
                 navigator.push(&route);
             }
         })
@@ -57,25 +67,79 @@ where
     /// basename is prepended.
     pub fn replace_callback(&self, route: R) -> Callback<()> {
         let navigator = self.navigator.clone();
+        let classifier = self.classifier.clone();
         Callback::from(move |()| {
             if let Some(navigator) = &navigator {
+                expect(classifier.as_ref(), NavigationDirection::Replace);
                 navigator.replace(&route);
             }
         })
     }
 
+    /// Returns `true` when the in-app history recorded by a
+    /// [`NavigationHistoryProvider`](crate::NavigationHistoryProvider) has an
+    /// entry before the current one. Always `false` without a provider.
+    #[must_use]
+    pub const fn can_go_back(&self) -> bool {
+        self.can_go_back
+    }
+
+    /// Create a callback that goes back when [`can_go_back`](Self::can_go_back)
+    /// and pushes `fallback` otherwise, so a "Back" button never leaves the
+    /// app when the page was opened directly.
+    pub fn go_back_or(&self, fallback: R) -> Callback<()> {
+        if self.can_go_back() {
+            self.go_back.clone()
+        } else {
+            self.push_callback(fallback)
+        }
+    }
+
+    /// Returns the browser href of `route`, prefixed with the router
+    /// basename, for anchors that are not rendered by
+    /// [`NavLink`](crate::NavLink).
+    ///
+    /// Under `basename="/app/v2"`, `href(&Route::Docs)` is `/app/v2/docs`.
+    #[must_use]
+    pub fn href(&self, route: &R) -> String {
+        let path = route.to_path();
+        match self.navigator.as_ref().and_then(Navigator::basename) {
+            Some(base) => with_basename(&path, base),
+            None => path
+        }
+    }
+
+    /// Returns [`href`](Self::href) with `params` appended as the query
+    /// string, e.g. `/app/v2/search?q=rust&page=2`. Empty `params` add no `?`.
+    #[must_use]
+    pub fn href_with_query(&self, route: &R, params: &QueryParams) -> String {
+        format!("{}{}", self.href(route), params.to_query_string())
+    }
+
     /// Create a callback that moves `delta` entries through history.
     #[must_use]
     pub fn go_callback(&self, delta: isize) -> Callback<()> {
         let navigator = self.navigator.clone();
+        let classifier = self.classifier.clone();
         Callback::from(move |()| {
             if let Some(navigator) = &navigator {
+                if delta != 0 {
+                    expect(classifier.as_ref(), NavigationDirection::Pop);
+                }
                 navigator.go(delta);
             }
         })
     }
 }
 
+/// Announces `kind` to the enclosing navigation providers, when there are
+/// any.
+fn expect(classifier: Option<&Classifier>, kind: NavigationDirection) {
+    if let Some(classifier) = classifier {
+        classifier.expect(kind);
+    }
+}
+
 /// Returns a [`Navigation`] handle for programmatic navigation.
 ///
 /// ```rust,ignore
@@ -110,11 +174,15 @@ where
     R: Routable + Clone + 'static
 {
     let navigator = use_navigator();
+    let can_go_back = use_can_go_back::<R>().unwrap_or(false);
+    let classifier = use_context::<Classifier>();
 
     let go_back = {
         let navigator = navigator.clone();
+        let classifier = classifier.clone();
         Callback::from(move |()| {
             if let Some(navigator) = &navigator {
+                expect(classifier.as_ref(), NavigationDirection::Pop);
                 navigator.back();
             }
         })
@@ -122,8 +190,10 @@ where
 
     let go_forward = {
         let navigator = navigator.clone();
+        let classifier = classifier.clone();
         Callback::from(move |()| {
             if let Some(navigator) = &navigator {
+                expect(classifier.as_ref(), NavigationDirection::Pop);
                 navigator.forward();
             }
         })
@@ -131,6 +201,8 @@ where
 
     Navigation {
         navigator,
+        can_go_back,
+        classifier,
         go_back,
         go_forward,
         marker: PhantomData
@@ -139,10 +211,24 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::marker::PhantomData;
-
     use super::*;
 
+    /// A handle around `navigator` with no-op `go_back`/`go_forward` and no
+    /// providers.
+    fn navigation<R>(navigator: Option<Navigator>) -> Navigation<R>
+    where
+        R: Routable + Clone + 'static
+    {
+        Navigation {
+            navigator,
+            can_go_back: false,
+            classifier: None,
+            go_back: Callback::from(|()| {}),
+            go_forward: Callback::from(|()| {}),
+            marker: PhantomData
+        }
+    }
+
     #[test]
     fn navigation_struct_creation() {
         #[derive(Clone, PartialEq, Debug, Routable)]
@@ -153,12 +239,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let _ = nav.go_back;
         let _ = nav.go_forward;
@@ -172,12 +253,7 @@ mod tests {
             Test
         }
 
-        let nav1 = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav1 = navigation::<TestRoute>(None);
 
         let nav2 = nav1;
         let _ = nav2.go_back;
@@ -192,12 +268,7 @@ mod tests {
             Test
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let debug_str = format!("{nav:?}");
         assert!(debug_str.contains("Navigation"));
@@ -211,12 +282,7 @@ mod tests {
             Test
         }
 
-        let nav1 = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav1 = navigation::<TestRoute>(None);
         let nav2 = nav1;
         let _ = nav1.go_back;
         let _ = nav2.go_back;
@@ -230,12 +296,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
         let _ = nav.push_callback(TestRoute::Home);
     }
 
@@ -247,12 +308,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
         let _ = nav.replace_callback(TestRoute::Home);
     }
 
@@ -264,12 +320,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
         let _ = nav.go_callback(-1);
     }
 
@@ -281,12 +332,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
         let callback = nav.go_callback(1);
         let _ = callback;
     }
@@ -299,12 +345,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
         let callback = nav.go_callback(0);
         let _ = callback;
     }
@@ -317,12 +358,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
         let callback = nav.go_callback(-10);
         let _ = callback;
     }
@@ -335,12 +371,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
         let callback = nav.go_callback(10);
         let _ = callback;
     }
@@ -353,12 +384,7 @@ mod tests {
             User { id: String }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let route = TestRoute::User {
             id: "123".to_string()
@@ -375,12 +401,7 @@ mod tests {
             Post { year: u32 }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let route = TestRoute::Post {
             year: 2024
@@ -397,12 +418,7 @@ mod tests {
             Settings { section: String }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let route = TestRoute::Settings {
             section: "profile".to_string()
@@ -419,12 +435,7 @@ mod tests {
             Api { version: u32 }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let route = TestRoute::Api {
             version: 1
@@ -443,12 +454,7 @@ mod tests {
             About
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let push_callback = nav.push_callback(TestRoute::Home);
         let replace_callback = nav.replace_callback(TestRoute::About);
@@ -467,12 +473,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let callback1 = nav.push_callback(TestRoute::Home);
         let callback2 = nav.push_callback(TestRoute::Home);
@@ -497,12 +498,7 @@ mod tests {
             Date
         }
 
-        let nav = Navigation::<ComplexRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<ComplexRoute>(None);
 
         let _ = nav.push_callback(ComplexRoute::Home);
         let _ = nav.push_callback(ComplexRoute::Users);
@@ -518,12 +514,7 @@ mod tests {
             Search { query: String }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let query = "rust programming".to_string();
         let route = TestRoute::Search {
@@ -542,12 +533,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let _ = nav.go_back;
     }
@@ -560,12 +546,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let _ = nav.go_forward;
     }
@@ -590,12 +571,7 @@ mod tests {
             Faq
         }
 
-        let nav = Navigation::<MultiVariantRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<MultiVariantRoute>(None);
 
         let _ = nav.push_callback(MultiVariantRoute::Home);
         let _ = nav.push_callback(MultiVariantRoute::About);
@@ -614,12 +590,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let min_delta = nav.go_callback(isize::MIN);
         let max_delta = nav.go_callback(isize::MAX);
@@ -640,12 +611,7 @@ mod tests {
             Item { name: String }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let route = TestRoute::Item {
             name: String::new()
@@ -662,12 +628,7 @@ mod tests {
             Tag { name: String }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let route = TestRoute::Tag {
             name: "rust-lang".to_string()
@@ -684,12 +645,7 @@ mod tests {
             Emoji { emoji: String }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let route = TestRoute::Emoji {
             emoji: "🦀".to_string()
@@ -706,12 +662,7 @@ mod tests {
             Number { num: u64 }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let route = TestRoute::Number {
             num: 0
@@ -734,12 +685,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let debug_str = format!("{nav:?}");
         assert!(debug_str.contains("Navigation"));
@@ -761,12 +707,7 @@ mod tests {
             Step3
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let step1_callback = nav.push_callback(TestRoute::Step1);
         let step2_callback = nav.push_callback(TestRoute::Step2);
@@ -787,12 +728,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let push_cb = nav.push_callback(TestRoute::Home);
         let replace_cb = nav.replace_callback(TestRoute::Home);
@@ -813,12 +749,7 @@ mod tests {
             }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let route = TestRoute::Member {
             org_id:    "org1".to_string(),
@@ -837,12 +768,7 @@ mod tests {
             Filter { min: u32, max: u32 }
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let route = TestRoute::Filter {
             min: 0, max: 100
@@ -859,12 +785,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let callback = nav.go_callback(-5);
         let _ = callback;
@@ -878,12 +799,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let _nav_back = &nav.go_back;
         let _nav_forward = &nav.go_forward;
@@ -904,19 +820,9 @@ mod tests {
             Page
         }
 
-        let nav1 = Navigation::<Route1> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav1 = navigation::<Route1>(None);
 
-        let nav2 = Navigation::<Route2> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav2 = navigation::<Route2>(None);
 
         let _ = nav1.push_callback(Route1::Home);
         let _ = nav2.push_callback(Route2::Page);
@@ -930,12 +836,7 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let _ = nav.push_callback(TestRoute::Home);
         let _ = nav.push_callback(TestRoute::Home);
@@ -950,15 +851,31 @@ mod tests {
             Home
         }
 
-        let nav = Navigation::<TestRoute> {
-            go_back:    Callback::from(|()| {}),
-            go_forward: Callback::from(|()| {}),
-            navigator:  None,
-            marker:     PhantomData
-        };
+        let nav = navigation::<TestRoute>(None);
 
         let _ = nav.replace_callback(TestRoute::Home);
         let _ = nav.replace_callback(TestRoute::Home);
         let _ = nav.replace_callback(TestRoute::Home);
     }
+
+    #[test]
+    fn navigation_href_without_router_is_route_path() {
+        #[derive(Clone, PartialEq, Debug, Routable)]
+        enum TestRoute {
+            #[at("/search")]
+            Search
+        }
+
+        let nav = navigation::<TestRoute>(None);
+
+        assert_eq!(nav.href(&TestRoute::Search), "/search");
+        assert_eq!(
+            nav.href_with_query(&TestRoute::Search, &QueryParams::parse("q=a b&page=2")),
+            "/search?q=a+b&page=2"
+        );
+        assert_eq!(
+            nav.href_with_query(&TestRoute::Search, &QueryParams::new()),
+            "/search"
+        );
+    }
 }
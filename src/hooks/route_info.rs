@@ -2,11 +2,27 @@
 // SPDX-License-Identifier: MIT
 
 pub mod active;
+pub mod async_labels;
+pub mod breadcrumb_config;
 pub mod breadcrumbs;
 pub mod info;
+pub mod label_providers;
+pub mod params;
+pub mod route_breadcrumbs;
 
-pub use active::{use_is_active, use_is_exact_active, use_is_partial_active};
+pub use active::{
+    use_active_index, use_active_route_among, use_is_active, use_is_exact_active,
+    use_is_partial_active
+};
+pub use async_labels::{AsyncBreadcrumbLabelProvider, LabelFuture};
+pub use breadcrumb_config::{BreadcrumbConfig, BreadcrumbRule};
 pub use breadcrumbs::{
     BreadcrumbItem, BreadcrumbLabelProvider, BreadcrumbLabelProviderContext, use_breadcrumbs
 };
-pub use info::use_route_info;
+pub use info::{RouteInfo, use_route_info};
+pub use label_providers::{
+    BreadcrumbContext, FnLabelProvider, MapLabelProvider, RouteLabelProvider,
+    RouteLabelProviderContext
+};
+pub use params::{route_param, use_route_param};
+pub use route_breadcrumbs::{BreadcrumbTree, RouteParent, use_route_breadcrumbs};
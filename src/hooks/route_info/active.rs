@@ -4,44 +4,96 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-/// Returns `true` when the given route exactly matches the current URL.
+use crate::{Match, active_link::match_specificity};
+
+/// Returns `true` when the given route is active under `mode`, with the same
+/// rules as a [`NavLink`](crate::NavLink) using that mode.
+///
+/// ```rust,ignore
+/// let docs_open = use_is_active(Route::Docs, Match::Partial);
+/// ```
 #[hook]
-pub fn use_is_active<R>(route: R) -> bool
+pub fn use_is_active<R>(route: R, mode: Match) -> bool
 where
     R: Routable + Clone + PartialEq + 'static
 {
     let current = use_route::<R>();
-    current.as_ref() == Some(&route)
+    current.is_some_and(|current| mode.is_active(&route, &current))
 }
 
 /// Returns `true` when the given route exactly matches the current URL.
 ///
-/// This is an alias for [`use_is_active`]; both perform exact matching.
+/// Shorthand for [`use_is_active`] with [`Match::Exact`].
 #[hook]
 pub fn use_is_exact_active<R>(route: R) -> bool
 where
     R: Routable + Clone + PartialEq + 'static
 {
-    let current = use_route::<R>();
-    current.as_ref() == Some(&route)
+    use_is_active(route, Match::Exact)
 }
 
 /// Returns `true` when the current URL starts with the given route's path.
 ///
-/// Matches segment-wise, so `/docs` matches `/docs/api` but not
-/// `/documentation`. A root route (`"/"`) matches only the root path, so a
-/// Home link is not reported active on every page.
+/// Shorthand for [`use_is_active`] with [`Match::Partial`]: matches
+/// segment-wise, so `/docs` matches `/docs/api` but not `/documentation`. A
+/// root route (`"/"`) matches only the root path, so a Home link is not
+/// reported active on every page.
 #[hook]
 pub fn use_is_partial_active<R>(route: R) -> bool
+where
+    R: Routable + Clone + PartialEq + 'static
+{
+    use_is_active(route, Match::Partial)
+}
+
+/// Returns the index of the best match for the current route among
+/// `routes`, for tab bars and segmented controls.
+///
+/// An exact match wins; otherwise the route with the longest segment-wise
+/// path prefix of the current path does, so `/docs/api` selects a `/docs`
+/// tab. Ties go to the earlier route. Returns `None` when nothing matches.
+#[hook]
+pub fn use_active_index<R>(routes: &[R]) -> Option<usize>
 where
     R: Routable + Clone + PartialEq + 'static
 {
     let current = use_route::<R>();
-    current.as_ref().is_some_and(|current_route| {
-        let target_path = route.to_path();
-        let current_path = current_route.to_path();
-        crate::active_link::is_path_prefix(&target_path, &current_path)
-    })
+    current.and_then(|current| best_match(routes, &current))
+}
+
+/// Returns the most specific of `routes` that is active, chosen like
+/// [`use_active_index`], so only one sidebar item lights up when both
+/// `/settings` and `/settings/profile` match.
+#[hook]
+pub fn use_active_route_among<R>(routes: &[R]) -> Option<R>
+where
+    R: Routable + Clone + PartialEq + 'static
+{
+    let index = use_active_index(routes);
+    index.map(|index| routes[index].clone())
+}
+
+/// Returns the index of the route in `routes` that best matches `current`:
+/// an exact match, else the longest partial match.
+fn best_match<R>(routes: &[R], current: &R) -> Option<usize>
+where
+    R: Routable + PartialEq
+{
+    if let Some(exact) = routes.iter().position(|route| route == current) {
+        return Some(exact);
+    }
+    let current = current.to_path();
+    routes
+        .iter()
+        .enumerate()
+        .filter_map(|(i, route)| {
+            match_specificity(&route.to_path(), &current).map(|rank| (i, rank))
+        })
+        .fold(None, |best: Option<(usize, usize)>, (i, rank)| match best {
+            Some((_, best_rank)) if best_rank >= rank => best,
+            _ => Some((i, rank))
+        })
+        .map(|(i, _)| i)
 }
 
 #[cfg(test)]
@@ -58,7 +110,7 @@ mod tests {
 
     #[test]
     fn use_is_active_returns_bool() {
-        let _ = use_is_active(TestRoute::Home);
+        let _ = use_is_active(TestRoute::Home, Match::Exact);
     }
 
     #[test]
@@ -66,6 +118,52 @@ mod tests {
         let _ = use_is_exact_active(TestRoute::Home);
     }
 
+    #[derive(Clone, PartialEq, Debug, Routable)]
+    enum SideRoute {
+        #[at("/")]
+        Home,
+        #[at("/settings")]
+        Settings,
+        #[at("/settings/profile")]
+        Profile,
+        #[at("/settings/profile/avatar")]
+        Avatar,
+        #[at("/about")]
+        About
+    }
+
+    #[test]
+    fn best_match_prefers_exact_match() {
+        let routes = [SideRoute::Settings, SideRoute::Profile];
+        assert_eq!(best_match(&routes, &SideRoute::Settings), Some(0));
+        assert_eq!(best_match(&routes, &SideRoute::Profile), Some(1));
+    }
+
+    #[test]
+    fn best_match_picks_longest_prefix() {
+        let routes = [SideRoute::Home, SideRoute::Settings, SideRoute::Profile];
+        assert_eq!(best_match(&routes, &SideRoute::Avatar), Some(2));
+        let routes = [SideRoute::Profile, SideRoute::Settings];
+        assert_eq!(best_match(&routes, &SideRoute::Avatar), Some(0));
+    }
+
+    #[test]
+    fn best_match_ignores_root_and_unrelated_routes() {
+        let routes = [SideRoute::Home, SideRoute::About];
+        assert_eq!(best_match(&routes, &SideRoute::Profile), None);
+        assert_eq!(best_match::<SideRoute>(&[], &SideRoute::Home), None);
+    }
+
+    #[test]
+    fn use_active_index_returns_option() {
+        let _ = use_active_index(&[SideRoute::Home, SideRoute::Settings]);
+    }
+
+    #[test]
+    fn use_active_route_among_returns_option() {
+        let _ = use_active_route_among(&[SideRoute::Home, SideRoute::Settings]);
+    }
+
     #[test]
     fn use_is_partial_active_returns_bool() {
         let _ = use_is_partial_active(TestRoute::Docs);
@@ -97,12 +195,12 @@ mod tests {
 
     #[test]
     fn use_is_active_with_home_route() {
-        let _ = use_is_active(TestRoute::Home);
+        let _ = use_is_active(TestRoute::Home, Match::Exact);
     }
 
     #[test]
     fn use_is_active_with_docs_route() {
-        let _ = use_is_active(TestRoute::Docs);
+        let _ = use_is_active(TestRoute::Docs, Match::Exact);
     }
 
     #[test]
@@ -374,9 +472,9 @@ mod tests {
             Contact
         }
 
-        let _ = use_is_active(MultiRoute::Home);
-        let _ = use_is_active(MultiRoute::About);
-        let _ = use_is_active(MultiRoute::Contact);
+        let _ = use_is_active(MultiRoute::Home, Match::Exact);
+        let _ = use_is_active(MultiRoute::About, Match::Exact);
+        let _ = use_is_active(MultiRoute::Contact, Match::Exact);
     }
 
     #[test]
@@ -0,0 +1,218 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Asynchronous breadcrumb labels.
+//!
+//! An [`AsyncBreadcrumbLabelProvider`] attached to a
+//! [`BreadcrumbLabelProviderContext`](super::BreadcrumbLabelProviderContext)
+//! resolves labels that need a fetch, such as a user's name for
+//! `/users/42`. [`use_breadcrumbs`](super::use_breadcrumbs) shows the
+//! placeholder (or the synchronous label) first and re-renders once the
+//! future resolves. Results are cached per path for the lifetime of the
+//! context, and a path requested by several components is loaded once.
+
+use std::{cell::RefCell, collections::HashMap, future::Future, pin::Pin, rc::Rc};
+
+use yew::prelude::*;
+
+/// A boxed, non-`Send` future resolving to a breadcrumb label.
+pub type LabelFuture = Pin<Box<dyn Future<Output = String>>>;
+
+/// A trait for breadcrumb labels that are loaded asynchronously.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::{AsyncBreadcrumbLabelProvider, LabelFuture};
+///
+/// struct UserNames;
+///
+/// impl AsyncBreadcrumbLabelProvider for UserNames {
+///     fn load_label(&self, path: &str) -> Option<LabelFuture> {
+///         let id = path.strip_prefix("/users/")?.to_string();
+///         Some(Box::pin(async move {
+///             // e.g. fetch `/api/users/{id}` and return the name
+///             format!("User {id}")
+///         }))
+///     }
+/// }
+/// ```
+pub trait AsyncBreadcrumbLabelProvider {
+    /// Starts loading the label of `path`, or returns `None` when the path
+    /// has no asynchronous label and the synchronous one should be kept.
+    ///
+    /// The path arrives percent-decoded, as for
+    /// [`BreadcrumbLabelProvider`](super::BreadcrumbLabelProvider). The
+    /// future is polled at most once per path and context; a failed load
+    /// should resolve to a fallback label rather than never completing.
+    fn load_label(&self, path: &str) -> Option<LabelFuture>;
+}
+
+/// A path whose label future has been created but not resolved.
+struct Pending {
+    future:  Option<LabelFuture>,
+    waiters: Vec<UseForceUpdateHandle>
+}
+
+/// Provider, cache, and in-flight loads shared by every clone of a context.
+pub(super) struct AsyncLabels {
+    provider: Rc<dyn AsyncBreadcrumbLabelProvider>,
+    /// `None` records a path the provider declined.
+    cache:    RefCell<HashMap<String, Option<String>>>,
+    pending:  RefCell<HashMap<String, Pending>>
+}
+
+impl AsyncLabels {
+    pub(super) fn new(provider: Rc<dyn AsyncBreadcrumbLabelProvider>) -> Self {
+        Self {
+            provider,
+            cache: RefCell::default(),
+            pending: RefCell::default()
+        }
+    }
+
+    pub(super) fn provider(&self) -> &Rc<dyn AsyncBreadcrumbLabelProvider> {
+        &self.provider
+    }
+
+    /// Returns the label to show for `path` right now. While a load is
+    /// outstanding this is `placeholder`, or `fallback` without one, and
+    /// `true` is returned so the caller subscribes with [`Self::wait`].
+    pub(super) fn label(
+        &self,
+        path: &str,
+        fallback: String,
+        placeholder: Option<&str>
+    ) -> (String, bool) {
+        if let Some(cached) = self.cache.borrow().get(path) {
+            return (cached.clone().unwrap_or(fallback), false);
+        }
+        if !self.pending.borrow().contains_key(path) {
+            let Some(future) = self.provider.load_label(path) else {
+                self.cache.borrow_mut().insert(path.to_string(), None);
+                return (fallback, false);
+            };
+            self.pending.borrow_mut().insert(
+                path.to_string(),
+                Pending {
+                    future:  Some(future),
+                    waiters: Vec::new()
+                }
+            );
+        }
+        (placeholder.map_or(fallback, ToString::to_string), true)
+    }
+
+    /// Re-renders `waiter` once the label of `path` resolves, polling the
+    /// future on first subscription.
+    pub(super) fn wait(self: &Rc<Self>, path: &str, waiter: UseForceUpdateHandle) {
+        let future = {
+            let mut pending = self.pending.borrow_mut();
+            let Some(entry) = pending.get_mut(path) else {
+                // Resolved between render and effect.
+                waiter.force_update();
+                return;
+            };
+            entry.waiters.push(waiter);
+            entry.future.take()
+        };
+        let Some(future) = future else {
+            return;
+        };
+
+        let labels = Rc::clone(self);
+        let path = path.to_string();
+        yew::platform::spawn_local(async move {
+            let label = future.await;
+            labels.cache.borrow_mut().insert(path.clone(), Some(label));
+            let waiters = labels
+                .pending
+                .borrow_mut()
+                .remove(&path)
+                .map(|entry| entry.waiters)
+                .unwrap_or_default();
+            for waiter in waiters {
+                waiter.force_update();
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    struct Users {
+        calls: Cell<u32>
+    }
+
+    impl AsyncBreadcrumbLabelProvider for Users {
+        fn load_label(&self, path: &str) -> Option<LabelFuture> {
+            self.calls.set(self.calls.get() + 1);
+            let id = path.strip_prefix("/users/")?.to_string();
+            Some(Box::pin(async move { format!("User {id}") }))
+        }
+    }
+
+    fn labels() -> (Rc<Users>, AsyncLabels) {
+        let users = Rc::new(Users {
+            calls: Cell::new(0)
+        });
+        (Rc::clone(&users), AsyncLabels::new(users))
+    }
+
+    #[test]
+    fn label_falls_back_while_loading() {
+        let (_, labels) = labels();
+        assert_eq!(
+            labels.label("/users/42", "/users/42".into(), None),
+            ("/users/42".to_string(), true)
+        );
+    }
+
+    #[test]
+    fn label_uses_placeholder_while_loading() {
+        let (_, labels) = labels();
+        assert_eq!(
+            labels.label("/users/42", "/users/42".into(), Some("…")),
+            ("…".to_string(), true)
+        );
+    }
+
+    #[test]
+    fn label_starts_one_load_per_path() {
+        let (users, labels) = labels();
+        let _ = labels.label("/users/42", String::new(), None);
+        let _ = labels.label("/users/42", String::new(), None);
+        assert_eq!(users.calls.get(), 1);
+    }
+
+    #[test]
+    fn declined_path_is_cached_and_keeps_fallback() {
+        let (users, labels) = labels();
+        assert_eq!(
+            labels.label("/about", "About".into(), Some("…")),
+            ("About".to_string(), false)
+        );
+        assert_eq!(
+            labels.label("/about", "About".into(), Some("…")),
+            ("About".to_string(), false)
+        );
+        assert_eq!(users.calls.get(), 1);
+    }
+
+    #[test]
+    fn cached_label_is_returned() {
+        let (_, labels) = labels();
+        labels
+            .cache
+            .borrow_mut()
+            .insert("/users/7".into(), Some("Ada".into()));
+        assert_eq!(
+            labels.label("/users/7", String::new(), None),
+            ("Ada".to_string(), false)
+        );
+    }
+}
@@ -0,0 +1,259 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Rules that reshape the URL-derived breadcrumb trail.
+//!
+//! URLs often carry technical segments that make poor crumbs:
+//! `/org/acme/p/123/tab/settings` yields a crumb for every prefix, including
+//! `/org`, `/org/acme/p`, and `/org/acme/p/123/tab`. A [`BreadcrumbConfig`]
+//! provided through a `ContextProvider` tells
+//! [`use_breadcrumbs`](super::use_breadcrumbs) which crumbs to hide, which
+//! to merge into one, and which to rename.
+//!
+//! Rules match the crumb's whole path against a [`PathPattern`], so
+//! `/org/:org/p/:id/tab` hides exactly the `tab` crumb under any project.
+
+use crate::utils::{PathParams, PathPattern};
+
+/// One breadcrumb rule of a [`BreadcrumbConfig`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BreadcrumbRule {
+    /// Drops every crumb whose path matches.
+    Hide(PathPattern),
+    /// Drops the parent of every crumb whose path matches, so the two
+    /// segments read as one crumb: with `/org/:org/p/:id`, `/org/acme/p`
+    /// disappears and `/org/acme/p/123` stays.
+    Merge(PathPattern),
+    /// Replaces the label of every crumb whose path matches with
+    /// `template`, in which `{name}` is replaced by the decoded `:name`
+    /// parameter.
+    Rename {
+        /// Paths the rule applies to.
+        pattern:  PathPattern,
+        /// Label template, e.g. `"Project {id}"`.
+        template: String
+    }
+}
+
+/// Hide, merge, and rename rules for
+/// [`use_breadcrumbs`](super::use_breadcrumbs).
+///
+/// Rules never remove the last crumb, so the trail always ends at the
+/// current page. A rename takes precedence over every label provider.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::{BreadcrumbConfig, utils::PathPattern};
+///
+/// let config = BreadcrumbConfig::new()
+///     .hide(PathPattern::parse("/org")?)
+///     .hide(PathPattern::parse("/org/:org/p/:id/tab")?)
+///     .merge(PathPattern::parse("/org/:org/p/:id")?)
+///     .rename(PathPattern::parse("/org/:org/p/:id")?, "Project {id}");
+///
+/// assert!(config.hides("/org/acme/p/123/tab"));
+/// assert!(config.merges("/org/acme/p/123"));
+/// assert_eq!(
+///     config.renamed("/org/acme/p/123").as_deref(),
+///     Some("Project 123")
+/// );
+/// # Ok::<(), yew_nav_link::NavError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BreadcrumbConfig {
+    rules: Vec<BreadcrumbRule>
+}
+
+impl BreadcrumbConfig {
+    /// Creates a configuration without rules.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            rules: Vec::new()
+        }
+    }
+
+    /// Adds a [`BreadcrumbRule::Hide`] rule.
+    #[must_use]
+    pub fn hide(self, pattern: PathPattern) -> Self {
+        self.rule(BreadcrumbRule::Hide(pattern))
+    }
+
+    /// Adds a [`BreadcrumbRule::Merge`] rule.
+    #[must_use]
+    pub fn merge(self, pattern: PathPattern) -> Self {
+        self.rule(BreadcrumbRule::Merge(pattern))
+    }
+
+    /// Adds a [`BreadcrumbRule::Rename`] rule.
+    #[must_use]
+    pub fn rename(self, pattern: PathPattern, template: impl Into<String>) -> Self {
+        self.rule(BreadcrumbRule::Rename {
+            pattern,
+            template: template.into()
+        })
+    }
+
+    /// Adds any rule.
+    #[must_use]
+    pub fn rule(mut self, rule: BreadcrumbRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Returns the rules in the order they were added.
+    #[must_use]
+    pub fn rules(&self) -> &[BreadcrumbRule] {
+        &self.rules
+    }
+
+    /// Returns `true` when a hide rule matches `path`.
+    #[must_use]
+    pub fn hides(&self, path: &str) -> bool {
+        self.rules.iter().any(|rule| match rule {
+            BreadcrumbRule::Hide(pattern) => pattern.matches(path).is_some(),
+            _ => false
+        })
+    }
+
+    /// Returns `true` when a merge rule matches `path`, i.e. the crumb
+    /// before it is dropped.
+    #[must_use]
+    pub fn merges(&self, path: &str) -> bool {
+        self.rules.iter().any(|rule| match rule {
+            BreadcrumbRule::Merge(pattern) => pattern.matches(path).is_some(),
+            _ => false
+        })
+    }
+
+    /// Returns the label of the first rename rule matching `path`.
+    #[must_use]
+    pub fn renamed(&self, path: &str) -> Option<String> {
+        self.rules.iter().find_map(|rule| match rule {
+            BreadcrumbRule::Rename {
+                pattern,
+                template
+            } => pattern
+                .matches(path)
+                .map(|params| fill_template(template, &params)),
+            _ => None
+        })
+    }
+
+    /// Returns which of the crumb `paths` (root first) remain visible.
+    pub(super) fn visible(&self, paths: &[String]) -> Vec<bool> {
+        let mut visible: Vec<bool> = paths.iter().map(|path| !self.hides(path)).collect();
+        for (i, path) in paths.iter().enumerate().skip(1) {
+            if self.merges(path) {
+                visible[i - 1] = false;
+            }
+        }
+        if let Some(last) = visible.last_mut() {
+            *last = true;
+        }
+        visible
+    }
+}
+
+/// Replaces every `{name}` in `template` with the parameter `name`. Unknown
+/// names and unmatched braces are kept verbatim.
+fn fill_template(template: &str, params: &PathParams) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        if let Some((close, value)) = after
+            .find('}')
+            .and_then(|close| params.get(&after[..close]).map(|value| (close, value)))
+        {
+            out.push_str(value);
+            rest = &after[close + 1..];
+        } else {
+            out.push('{');
+            rest = after;
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(template: &str) -> PathPattern {
+        PathPattern::parse(template).unwrap()
+    }
+
+    fn paths(list: &[&str]) -> Vec<String> {
+        list.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn hide_and_merge_select_visible_crumbs() {
+        let config = BreadcrumbConfig::new()
+            .hide(pattern("/org"))
+            .hide(pattern("/org/:org/p/:id/tab"))
+            .merge(pattern("/org/:org/p/:id"));
+        let trail = paths(&[
+            "/",
+            "/org",
+            "/org/acme",
+            "/org/acme/p",
+            "/org/acme/p/123",
+            "/org/acme/p/123/tab",
+            "/org/acme/p/123/tab/settings"
+        ]);
+        assert_eq!(
+            config.visible(&trail),
+            [true, false, true, false, true, false, true]
+        );
+    }
+
+    #[test]
+    fn last_crumb_is_never_hidden() {
+        let config = BreadcrumbConfig::new().hide(pattern("/docs"));
+        assert_eq!(config.visible(&paths(&["/", "/docs"])), [true, true]);
+    }
+
+    #[test]
+    fn merge_on_root_is_ignored() {
+        let config = BreadcrumbConfig::new().merge(pattern("/"));
+        assert_eq!(config.visible(&paths(&["/", "/a"])), [true, true]);
+    }
+
+    #[test]
+    fn rename_fills_decoded_params() {
+        let config = BreadcrumbConfig::new()
+            .rename(pattern("/org/:org"), "Org {org}")
+            .rename(pattern("/org/:org"), "ignored");
+        assert_eq!(
+            config.renamed("/org/acme%20inc").as_deref(),
+            Some("Org acme inc")
+        );
+        assert_eq!(config.renamed("/org"), None);
+    }
+
+    #[test]
+    fn template_keeps_unknown_placeholders() {
+        let params: PathParams = std::iter::once(("id", "7")).collect();
+        assert_eq!(fill_template("{id}/{x}/{", &params), "7/{x}/{");
+        assert_eq!(fill_template("{{id}}", &params), "{7}");
+    }
+
+    #[test]
+    fn rules_keep_insertion_order() {
+        let config = BreadcrumbConfig::new()
+            .merge(pattern("/a/:b"))
+            .hide(pattern("/a"));
+        assert_eq!(
+            config.rules(),
+            [
+                BreadcrumbRule::Merge(pattern("/a/:b")),
+                BreadcrumbRule::Hide(pattern("/a"))
+            ]
+        );
+    }
+}
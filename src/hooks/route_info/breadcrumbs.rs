@@ -1,11 +1,18 @@
 // SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
-use std::rc::Rc;
+use std::{borrow::Cow, rc::Rc};
 
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use super::{
+    async_labels::{AsyncBreadcrumbLabelProvider, AsyncLabels},
+    breadcrumb_config::BreadcrumbConfig,
+    label_providers::{BreadcrumbContext, RouteLabelProviderContext}
+};
+use crate::utils::{EncodeSet, decode_cow_with, path_segments, with_basename};
+
 /// A trait for providing custom breadcrumb labels.
 pub trait BreadcrumbLabelProvider: Send + Sync {
     /// Returns a human-readable label for the given path.
@@ -16,18 +23,31 @@ pub trait BreadcrumbLabelProvider: Send + Sync {
     fn label_for_path(&self, path: &str) -> String;
 }
 
-/// Percent-decodes a path for display, keeping the raw text when the decoded
-/// bytes are not valid UTF-8.
-fn display_path(path: &str) -> String {
-    crate::utils::percent_decode(path).unwrap_or_else(|| path.to_string())
+/// Percent-decodes a path for display with the path-segment escaping rules
+/// (`+` stays literal), keeping the raw text when the decoded bytes are not
+/// valid UTF-8.
+///
+/// A path without `%` borrows the input. The crumb fields are owned
+/// `String`s, so storing the result still costs one copy; only the decode
+/// buffer is skipped.
+pub(super) fn display_path(path: &str) -> Cow<'_, str> {
+    decode_cow_with(path, EncodeSet::PathSegment).unwrap_or(Cow::Borrowed(path))
 }
 
 /// Yew context wrapper around a [`BreadcrumbLabelProvider`].
 ///
 /// Place an instance into the tree with `<ContextProvider<…>>` to override
 /// the default path-as-label behaviour of [`use_breadcrumbs`]. Equality is
-/// pointer-equality on the inner [`Rc`], so re-renders happen only when the
-/// concrete provider value changes.
+/// pointer-equality on the inner [`Rc`]s, so re-renders happen only when the
+/// concrete provider values change.
+///
+/// An [`AsyncBreadcrumbLabelProvider`] added with
+/// [`with_async_provider`](Self::with_async_provider) loads labels that need
+/// a fetch. Until a load resolves, the crumb shows the
+/// [`with_placeholder`](Self::with_placeholder) text, or the synchronous
+/// label without one. Resolved labels are cached per path and shared by
+/// every clone of the context, so create the context once (e.g. with
+/// `use_memo`) rather than on every render.
 ///
 /// The inner `Rc` is **not** publicly accessible — construct via
 /// [`BreadcrumbLabelProviderContext::new`] and read with
@@ -35,54 +55,153 @@ fn display_path(path: &str) -> String {
 /// lets future versions evolve the representation (e.g. a provider chain or
 /// internal cache) without breaking consumers.
 #[derive(Clone)]
-pub struct BreadcrumbLabelProviderContext(Rc<dyn BreadcrumbLabelProvider>);
+pub struct BreadcrumbLabelProviderContext {
+    provider:     Rc<dyn BreadcrumbLabelProvider>,
+    async_labels: Option<Rc<AsyncLabels>>,
+    placeholder:  Option<AttrValue>
+}
 
 impl BreadcrumbLabelProviderContext {
     /// Wraps the given provider so it can be passed to `ContextProvider`.
     #[must_use]
     pub fn new(provider: Rc<dyn BreadcrumbLabelProvider>) -> Self {
-        Self(provider)
+        Self {
+            provider,
+            async_labels: None,
+            placeholder: None
+        }
+    }
+
+    /// Adds an asynchronous provider whose labels replace the synchronous
+    /// ones once loaded. Replaces any earlier asynchronous provider and
+    /// starts with an empty cache.
+    #[must_use]
+    pub fn with_async_provider(self, provider: Rc<dyn AsyncBreadcrumbLabelProvider>) -> Self {
+        Self {
+            async_labels: Some(Rc::new(AsyncLabels::new(provider))),
+            ..self
+        }
+    }
+
+    /// Sets the label shown while an asynchronous label loads (e.g. `"…"`).
+    ///
+    /// Has no effect without
+    /// [`with_async_provider`](Self::with_async_provider).
+    #[must_use]
+    pub fn with_placeholder(self, placeholder: impl Into<AttrValue>) -> Self {
+        Self {
+            placeholder: Some(placeholder.into()),
+            ..self
+        }
     }
 
     /// Returns a clone of the inner [`Rc`] for callers that need to invoke
     /// the provider directly.
     #[must_use]
     pub fn provider(&self) -> Rc<dyn BreadcrumbLabelProvider> {
-        Rc::clone(&self.0)
+        Rc::clone(&self.provider)
+    }
+
+    /// Returns the asynchronous provider, if one was added.
+    #[must_use]
+    pub fn async_provider(&self) -> Option<Rc<dyn AsyncBreadcrumbLabelProvider>> {
+        self.async_labels
+            .as_ref()
+            .map(|labels| Rc::clone(labels.provider()))
+    }
+
+    /// Returns the label to show now for the decoded `path`, and whether an
+    /// asynchronous label is still loading. A `route_label` from a
+    /// [`RouteLabelProvider`](super::RouteLabelProvider) takes precedence over
+    /// the synchronous provider.
+    fn label(&self, path: &str, route_label: Option<String>) -> (String, bool) {
+        let label = route_label.unwrap_or_else(|| self.provider.label_for_path(path));
+        match &self.async_labels {
+            Some(labels) => labels.label(path, label, self.placeholder.as_deref()),
+            None => (label, false)
+        }
+    }
+
+    /// Re-renders `waiter` once the asynchronous label of `path` resolves.
+    fn wait(&self, path: &str, waiter: UseForceUpdateHandle) {
+        if let Some(labels) = &self.async_labels {
+            labels.wait(path, waiter);
+        }
     }
 }
 
 impl PartialEq for BreadcrumbLabelProviderContext {
     fn eq(&self, other: &Self) -> bool {
-        Rc::ptr_eq(&self.0, &other.0)
+        Rc::ptr_eq(&self.provider, &other.provider)
+            && match (&self.async_labels, &other.async_labels) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false
+            }
+            && self.placeholder == other.placeholder
     }
 }
 
 /// A single item in a breadcrumb trail.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct BreadcrumbItem<R> {
-    /// The route this breadcrumb points to.
-    pub route:     R,
+    /// The route this breadcrumb points to. When `is_recognized` is `false`
+    /// this is only a stand-in (the current route) and should not be linked.
+    pub route:         R,
+    /// Browser href of `path`, prefixed with the router basename.
+    pub href:          String,
+    /// Path of this crumb as it appears in the URL (still percent-encoded),
+    /// e.g. `/users/hello%20world`.
+    pub path:          String,
+    /// Percent-decoded last segment of `path`; empty for the root crumb.
+    pub segment:       String,
     /// Human-readable label for the breadcrumb.
-    pub label:     String,
+    pub label:         String,
+    /// Optional icon from the
+    /// [`RouteLabelProvider`](super::RouteLabelProvider), rendered before the
+    /// label by [`Breadcrumbs`](crate::Breadcrumbs).
+    pub icon:          Option<AttrValue>,
+    /// Position in the trail: `0` for the first crumb. Crumbs hidden by a
+    /// [`BreadcrumbConfig`] are not counted.
+    pub depth:         usize,
+    /// Whether `path` is the path of an actual route of `R`. Prefixes such
+    /// as `/users` when only `/users/:id` exists are not.
+    pub is_recognized: bool,
     /// Whether this breadcrumb represents the currently active route.
-    pub is_active: bool
+    pub is_active:     bool
 }
 
-/// Resolves `path` to its actual route, falling back to `fallback` when no
-/// real route matches.
+#[cfg(test)]
+impl<R> BreadcrumbItem<R> {
+    /// Test fixture: a recognized, icon-less crumb at depth `0` whose `path`
+    /// equals its `href`.
+    pub(crate) fn fixture(route: R, label: &str, href: &str, is_active: bool) -> Self {
+        Self {
+            route,
+            href: href.to_string(),
+            path: href.to_string(),
+            segment: String::new(),
+            label: label.to_string(),
+            icon: None,
+            depth: 0,
+            is_recognized: true,
+            is_active
+        }
+    }
+}
+
+/// Resolves `path` to the route that serializes back to exactly `path`, or
+/// `None` when no real route matches.
 ///
 /// [`Routable::recognize`] substitutes the `#[not_found]` route itself
 /// instead of returning `None`, so the result is accepted only when it
 /// round-trips back to the requested path. Without this check an intermediate
 /// breadcrumb for an unrouted prefix would link to the 404 page.
-fn recognized_or<R>(path: &str, fallback: &R) -> R
+fn recognized<R>(path: &str) -> Option<R>
 where
-    R: Routable + Clone
+    R: Routable
 {
-    R::recognize(path)
-        .filter(|route| route.to_path() == path)
-        .unwrap_or_else(|| fallback.clone())
+    R::recognize(path).filter(|route| route.to_path() == path)
 }
 
 /// Returns a list of [`BreadcrumbItem`]s representing the current navigation
@@ -91,12 +210,30 @@ where
 /// Each item's `route` is resolved from its own path prefix via
 /// [`Routable::recognize`], so parent breadcrumbs navigate to their actual
 /// routes. When a prefix does not correspond to any route in `R` (e.g.
-/// `/users` when only `/users/:id` exists), the item falls back to the
-/// current route, even when `R` declares a `#[not_found]` route.
+/// `/users` when only `/users/:id` exists), the item has
+/// `is_recognized == false` and its `route` falls back to the current route,
+/// even when `R` declares a `#[not_found]` route.
 ///
 /// Labels are percent-decoded: a route serialized as `/users/hello%20world`
 /// yields the default label `/users/hello world`, and a
 /// [`BreadcrumbLabelProvider`] receives the decoded path as well.
+///
+/// A [`RouteLabelProviderContext<R>`] in scope is asked first, with a
+/// [`BreadcrumbContext<R>`] carrying the recognized route, segment, depth,
+/// and locale of each crumb; crumbs it returns `None` for fall back to the
+/// [`BreadcrumbLabelProvider`], then to the decoded path. An asynchronous
+/// label still replaces either once loaded. The same provider may attach an
+/// icon to each crumb.
+///
+/// A [`BreadcrumbConfig`] in scope hides, merges, and renames crumbs by
+/// path pattern; a renamed crumb skips the label providers. Hidden crumbs
+/// are left out of the trail, so `depth`, both in the
+/// [`BreadcrumbContext<R>`] handed to providers and in the returned items,
+/// is the position among the visible crumbs.
+///
+/// Each item's `href` is its own path prefixed with the router basename,
+/// ready for a plain `<a href>`; under `basename="/app/v2"` the root item
+/// links to `/app/v2/`.
 #[hook]
 pub fn use_breadcrumbs<R>() -> Vec<BreadcrumbItem<R>>
 where
@@ -104,37 +241,96 @@ where
 {
     let current = use_route::<R>();
     let provider = use_context::<BreadcrumbLabelProviderContext>();
+    let navigator = use_navigator();
+    let basename = navigator
+        .as_ref()
+        .and_then(Navigator::basename)
+        .unwrap_or_default();
+    let config = use_context::<BreadcrumbConfig>();
+    let route_labels = use_context::<RouteLabelProviderContext<R>>();
+    let route_provider = route_labels
+        .as_ref()
+        .map(RouteLabelProviderContext::provider);
+    let locale = route_labels.as_ref().and_then(|ctx| ctx.locale().cloned());
+
+    let mut loading = Vec::new();
+    let mut label_for = |crumb: &BreadcrumbContext<R>| {
+        let route_label = route_provider
+            .as_ref()
+            .and_then(|provider| provider.label(crumb));
+        match &provider {
+            Some(ctx) => {
+                let (label, is_loading) = ctx.label(&crumb.path, route_label);
+                if is_loading {
+                    loading.push(crumb.path.clone());
+                }
+                label
+            }
+            None => route_label.unwrap_or_else(|| crumb.path.clone())
+        }
+    };
 
-    current.map_or_else(Vec::new, |route| {
+    let items = current.map_or_else(Vec::new, |route| {
         let path = route.to_path();
-        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
-        let mut items = Vec::new();
-        let mut built = String::new();
-        let root_label = provider
-            .as_ref()
-            .map_or_else(|| "/".to_string(), |p| p.0.label_for_path("/"));
-        items.push(BreadcrumbItem {
-            route:     recognized_or("/", &route),
-            label:     root_label,
-            is_active: segments.is_empty()
-        });
-        let total = segments.len();
-        for (i, segment) in segments.iter().enumerate() {
-            built.push('/');
-            built.push_str(segment);
-            let is_last = i + 1 == total;
-            let readable = display_path(&built);
-            let label = provider
-                .as_ref()
-                .map_or_else(|| readable.clone(), |p| p.0.label_for_path(&readable));
-            items.push(BreadcrumbItem {
-                route: recognized_or(&built, &route),
-                label,
-                is_active: is_last
-            });
+        let mut prefixes = vec![String::from("/")];
+        let mut segments = vec![""];
+        for segment in path_segments(&path) {
+            let parent = prefixes.last().map_or("", |p| p.trim_end_matches('/'));
+            prefixes.push(format!("{parent}/{segment}"));
+            segments.push(segment);
         }
-        items
-    })
+        let visible = config.as_ref().map_or_else(
+            || vec![true; prefixes.len()],
+            |config| config.visible(&prefixes)
+        );
+        let last = prefixes.len() - 1;
+
+        prefixes
+            .into_iter()
+            .zip(segments)
+            .enumerate()
+            .filter(|(i, _)| visible[*i])
+            .enumerate()
+            .map(|(depth, (i, (prefix, segment)))| {
+                let crumb = BreadcrumbContext {
+                    route: recognized::<R>(&prefix),
+                    path: display_path(&prefix).into_owned(),
+                    segment: display_path(segment).into_owned(),
+                    depth,
+                    locale: locale.clone()
+                };
+                let label = config
+                    .as_ref()
+                    .and_then(|config| config.renamed(&prefix))
+                    .unwrap_or_else(|| label_for(&crumb));
+                let icon = route_provider
+                    .as_ref()
+                    .and_then(|provider| provider.icon(&crumb));
+                BreadcrumbItem {
+                    href: with_basename(&prefix, basename),
+                    is_recognized: crumb.route.is_some(),
+                    route: crumb.route.unwrap_or_else(|| route.clone()),
+                    path: prefix,
+                    segment: crumb.segment,
+                    label,
+                    icon,
+                    depth,
+                    is_active: i == last
+                }
+            })
+            .collect()
+    });
+
+    let force_update = use_force_update();
+    use_effect_with(loading, move |loading| {
+        if let Some(ctx) = &provider {
+            for path in loading {
+                ctx.wait(path, force_update.clone());
+            }
+        }
+    });
+
+    items
 }
 
 #[cfg(test)]
@@ -198,11 +394,7 @@ mod tests {
 
     #[test]
     fn breadcrumb_item_new() {
-        let item = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "Home".to_string(),
-            is_active: true
-        };
+        let item = BreadcrumbItem::fixture(SimpleRoute::Home, "Home", "/", true);
         assert_eq!(item.label, "Home");
         assert!(item.is_active);
         assert_eq!(item.route.to_path(), "/");
@@ -210,22 +402,14 @@ mod tests {
 
     #[test]
     fn breadcrumb_item_inactive() {
-        let item = BreadcrumbItem {
-            route:     SimpleRoute::About,
-            label:     "About".to_string(),
-            is_active: false
-        };
+        let item = BreadcrumbItem::fixture(SimpleRoute::About, "About", "/", false);
         assert!(!item.is_active);
         assert_eq!(item.label, "About");
     }
 
     #[test]
     fn breadcrumb_item_clone_preserves_all_fields() {
-        let item1 = BreadcrumbItem {
-            route:     SimpleRoute::Api,
-            label:     "Root".to_string(),
-            is_active: true
-        };
+        let item1 = BreadcrumbItem::fixture(SimpleRoute::Api, "Root", "/", true);
         let item2 = item1.clone();
         assert_eq!(item1, item2);
         assert_eq!(item2.label, "Root");
@@ -234,71 +418,35 @@ mod tests {
 
     #[test]
     fn breadcrumb_item_eq_with_same_values() {
-        let item1 = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "Home".to_string(),
-            is_active: true
-        };
-        let item2 = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "Home".to_string(),
-            is_active: true
-        };
+        let item1 = BreadcrumbItem::fixture(SimpleRoute::Home, "Home", "/", true);
+        let item2 = BreadcrumbItem::fixture(SimpleRoute::Home, "Home", "/", true);
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn breadcrumb_item_neq_different_label() {
-        let item1 = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "Home".to_string(),
-            is_active: true
-        };
-        let item2 = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "Index".to_string(),
-            is_active: true
-        };
+        let item1 = BreadcrumbItem::fixture(SimpleRoute::Home, "Home", "/", true);
+        let item2 = BreadcrumbItem::fixture(SimpleRoute::Home, "Index", "/", true);
         assert_ne!(item1, item2);
     }
 
     #[test]
     fn breadcrumb_item_neq_different_state() {
-        let item1 = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "Home".to_string(),
-            is_active: true
-        };
-        let item2 = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "Home".to_string(),
-            is_active: false
-        };
+        let item1 = BreadcrumbItem::fixture(SimpleRoute::Home, "Home", "/", true);
+        let item2 = BreadcrumbItem::fixture(SimpleRoute::Home, "Home", "/", false);
         assert_ne!(item1, item2);
     }
 
     #[test]
     fn breadcrumb_item_neq_different_route() {
-        let item1 = BreadcrumbItem {
-            route:     SimpleRoute::Docs,
-            label:     "Docs".to_string(),
-            is_active: false
-        };
-        let item2 = BreadcrumbItem {
-            route:     SimpleRoute::Api,
-            label:     "Docs".to_string(),
-            is_active: false
-        };
+        let item1 = BreadcrumbItem::fixture(SimpleRoute::Docs, "Docs", "/", false);
+        let item2 = BreadcrumbItem::fixture(SimpleRoute::Api, "Docs", "/", false);
         assert_ne!(item1, item2);
     }
 
     #[test]
     fn breadcrumb_item_debug_contains_all_fields() {
-        let item = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "Home".to_string(),
-            is_active: true
-        };
+        let item = BreadcrumbItem::fixture(SimpleRoute::Home, "Home", "/", true);
         let debug_str = format!("{item:?}");
         assert!(debug_str.contains("BreadcrumbItem"));
         assert!(debug_str.contains("Home"));
@@ -309,53 +457,33 @@ mod tests {
     fn breadcrumb_item_long_label() {
         let label = "Extremely long breadcrumb label to test string handling in various scenarios"
             .to_string();
-        let item = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     label.clone(),
-            is_active: false
-        };
+        let item = BreadcrumbItem::fixture(SimpleRoute::Home, &label, "/", false);
         assert_eq!(item.label, label);
         assert!(!item.is_active);
     }
 
     #[test]
     fn breadcrumb_item_short_label() {
-        let item = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "a".to_string(),
-            is_active: true
-        };
+        let item = BreadcrumbItem::fixture(SimpleRoute::Home, "a", "/", true);
         assert_eq!(item.label, "a");
     }
 
     #[test]
     fn breadcrumb_item_clone_deep_copy() {
-        let item1 = BreadcrumbItem {
-            route:     SimpleRoute::ApiV1,
-            label:     "Deep".to_string(),
-            is_active: true
-        };
+        let item1 = BreadcrumbItem::fixture(SimpleRoute::ApiV1, "Deep", "/", true);
         let item2 = item1.clone();
         assert_eq!(item1, item2);
     }
 
     #[test]
     fn breadcrumb_item_root_path() {
-        let item = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "/".to_string(),
-            is_active: true
-        };
+        let item = BreadcrumbItem::fixture(SimpleRoute::Home, "/", "/", true);
         assert_eq!(item.route.to_path(), "/");
     }
 
     #[test]
     fn breadcrumb_item_nested_path() {
-        let item = BreadcrumbItem {
-            route:     SimpleRoute::ApiV1,
-            label:     "/docs/api/v1".to_string(),
-            is_active: true
-        };
+        let item = BreadcrumbItem::fixture(SimpleRoute::ApiV1, "/docs/api/v1", "/", true);
         assert_eq!(item.route.to_path(), "/docs/api/v1");
     }
 
@@ -395,26 +523,59 @@ mod tests {
     #[test]
     fn context_eq_same_rc() {
         let rc = Rc::new(TestLabelProvider);
-        let ctx1 = BreadcrumbLabelProviderContext(rc.clone());
-        let ctx2 = BreadcrumbLabelProviderContext(rc);
+        let ctx1 = BreadcrumbLabelProviderContext::new(rc.clone());
+        let ctx2 = BreadcrumbLabelProviderContext::new(rc);
         assert!(ctx1 == ctx2);
     }
 
     #[test]
     fn context_neq_different_rc() {
-        let ctx1 = BreadcrumbLabelProviderContext(Rc::new(TestLabelProvider));
-        let ctx2 = BreadcrumbLabelProviderContext(Rc::new(TestLabelProvider));
+        let ctx1 = BreadcrumbLabelProviderContext::new(Rc::new(TestLabelProvider));
+        let ctx2 = BreadcrumbLabelProviderContext::new(Rc::new(TestLabelProvider));
         assert!(ctx1 != ctx2);
     }
 
     #[test]
     fn context_clone_preserves_identity() {
         let rc = Rc::new(TestLabelProvider);
-        let ctx1 = BreadcrumbLabelProviderContext(rc);
+        let ctx1 = BreadcrumbLabelProviderContext::new(rc);
         let ctx2 = ctx1.clone();
         assert!(ctx1 == ctx2);
     }
 
+    struct NoAsyncLabels;
+
+    impl AsyncBreadcrumbLabelProvider for NoAsyncLabels {
+        fn load_label(&self, _path: &str) -> Option<crate::LabelFuture> {
+            None
+        }
+    }
+
+    #[test]
+    fn context_async_provider_changes_identity() {
+        let ctx = BreadcrumbLabelProviderContext::new(Rc::new(TestLabelProvider));
+        let with_async = ctx.clone().with_async_provider(Rc::new(NoAsyncLabels));
+        assert!(ctx != with_async);
+        assert!(with_async == with_async.clone());
+        assert!(ctx.async_provider().is_none());
+        assert!(with_async.async_provider().is_some());
+    }
+
+    #[test]
+    fn context_placeholder_changes_identity() {
+        let ctx = BreadcrumbLabelProviderContext::new(Rc::new(TestLabelProvider))
+            .with_async_provider(Rc::new(NoAsyncLabels));
+        assert!(ctx != ctx.clone().with_placeholder("…"));
+    }
+
+    #[test]
+    fn context_label_keeps_sync_label_when_async_declines() {
+        let ctx = BreadcrumbLabelProviderContext::new(Rc::new(TestLabelProvider))
+            .with_async_provider(Rc::new(NoAsyncLabels))
+            .with_placeholder("…");
+        assert_eq!(ctx.label("/docs", None), ("Docs".to_string(), false));
+    }
+
     #[test]
     fn use_breadcrumbs_simple_route() {
         let _result = use_breadcrumbs::<SimpleRoute>();
@@ -473,56 +634,50 @@ mod tests {
     }
 
     #[test]
-    fn recognized_or_returns_matching_route() {
-        let fallback = NotFoundRoute::Home;
-        let resolved = recognized_or::<NotFoundRoute>("/users/42", &fallback);
+    fn recognized_returns_matching_route() {
         assert_eq!(
-            resolved,
-            NotFoundRoute::User {
+            recognized::<NotFoundRoute>("/users/42"),
+            Some(NotFoundRoute::User {
                 id: "42".to_string()
-            }
+            })
         );
     }
 
     #[test]
-    fn recognized_or_falls_back_for_unrouted_prefix_with_not_found_route() {
-        let fallback = NotFoundRoute::User {
-            id: "42".to_string()
-        };
-        let resolved = recognized_or::<NotFoundRoute>("/users", &fallback);
-        assert_eq!(resolved, fallback);
+    fn recognized_rejects_unrouted_prefix_with_not_found_route() {
+        assert_eq!(recognized::<NotFoundRoute>("/users"), None);
     }
 
     #[test]
-    fn recognized_or_resolves_explicit_not_found_path() {
-        let fallback = NotFoundRoute::Home;
-        let resolved = recognized_or::<NotFoundRoute>("/404", &fallback);
-        assert_eq!(resolved, NotFoundRoute::NotFound);
+    fn recognized_resolves_explicit_not_found_path() {
+        assert_eq!(
+            recognized::<NotFoundRoute>("/404"),
+            Some(NotFoundRoute::NotFound)
+        );
+    }
+
+    #[test]
+    fn recognized_resolves_root() {
+        assert_eq!(recognized::<NotFoundRoute>("/"), Some(NotFoundRoute::Home));
     }
 
     #[test]
-    fn recognized_or_resolves_root() {
-        let fallback = NotFoundRoute::NotFound;
-        let resolved = recognized_or::<NotFoundRoute>("/", &fallback);
-        assert_eq!(resolved, NotFoundRoute::Home);
+    fn recognized_rejects_unrouted_prefix_without_not_found_route() {
+        assert_eq!(recognized::<ParamRoute>("/users"), None);
     }
 
     #[test]
-    fn recognized_or_falls_back_without_not_found_route() {
-        let fallback = ParamRoute::User {
-            id: "7".to_string()
-        };
-        let resolved = recognized_or::<ParamRoute>("/users", &fallback);
-        assert_eq!(resolved, fallback);
+    fn context_label_prefers_route_label() {
+        let ctx = BreadcrumbLabelProviderContext::new(Rc::new(TestLabelProvider));
+        assert_eq!(
+            ctx.label("/docs", Some("Documentation".into())),
+            ("Documentation".to_string(), false)
+        );
     }
 
     #[test]
     fn breadcrumb_item_neq_negatives() {
-        let item1 = BreadcrumbItem {
-            route:     SimpleRoute::Home,
-            label:     "Home".to_string(),
-            is_active: true
-        };
+        let item1 = BreadcrumbItem::fixture(SimpleRoute::Home, "Home", "/", true);
         let mut item2 = item1.clone();
         item2.label = "Other".to_string();
         assert_ne!(item1, item2);
@@ -1,17 +1,138 @@
 // SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
 // SPDX-License-Identifier: MIT
 
+//! Everything a page usually needs to know about the current URL, in one
+//! value.
+//!
+//! Pages tend to combine `use_route`, `use_location`, `use_query_params`,
+//! and `use_basename`; [`use_route_info`] reads them once and returns a
+//! [`RouteInfo`].
+
+use std::rc::Rc;
+
 use yew::prelude::*;
-use yew_router::prelude::*;
+use yew_router::{history::Location, prelude::*};
+
+use super::params::matched_template;
+use crate::{
+    hooks::use_basename,
+    utils::{PathParams, QueryParams, strip_basename}
+};
 
-/// Returns the currently active route, or `None` if no route is matched.
+/// The current route together with the URL it was recognized from.
+///
+/// Returned by [`use_route_info`]. Outside a router every field is empty:
+/// `route` is `None`, the paths are `""`, and `is_not_found` is `true`.
+#[derive(Clone, Debug)]
+pub struct RouteInfo<R> {
+    /// The matched route, or `None` when no route of `R` matches.
+    pub route:        Option<R>,
+    /// Browser path, including the router basename (e.g. `/app/users/7`).
+    pub path:         String,
+    /// Path with the basename removed, as seen by the router
+    /// (e.g. `/users/7`).
+    pub route_path:   String,
+    /// Parsed query string.
+    pub query:        QueryParams,
+    /// Fragment without the leading `#`, or `None` when empty.
+    pub fragment:     Option<String>,
+    /// The `#[at]` template the route was matched with (e.g. `/users/:id`),
+    /// or `None` when `is_not_found`.
+    pub template:     Option<&'static str>,
+    /// Decoded parameters captured by `template`; empty when `is_not_found`.
+    pub params:       PathParams,
+    /// `true` when no route matches or the route is the `#[not_found]` one.
+    pub is_not_found: bool,
+    location:         Option<Location>
+}
+
+impl<R> RouteInfo<R>
+where
+    R: Routable
+{
+    fn new(route: Option<R>, location: Option<Location>, basename: Option<&str>) -> Self {
+        let (path, query, hash) = location
+            .as_ref()
+            .map_or(("", "", ""), |l| (l.path(), l.query_str(), l.hash()));
+        let mut info = Self::from_url(route, path, query, hash, basename);
+        info.location = location;
+        info
+    }
+
+    fn from_url(
+        route: Option<R>,
+        path: &str,
+        query: &str,
+        hash: &str,
+        basename: Option<&str>
+    ) -> Self {
+        let route_path = basename.map_or(path, |base| strip_basename(path, base));
+        let fragment = Some(hash.trim_start_matches('#'))
+            .filter(|hash| !hash.is_empty())
+            .map(ToString::to_string);
+        let is_not_found = route
+            .as_ref()
+            .is_none_or(|route| R::not_found_route().as_ref() == Some(route));
+        // The `#[not_found]` route's own template never matched the URL.
+        let (template, params) = route
+            .as_ref()
+            .filter(|_| !is_not_found)
+            .and_then(|route| matched_template::<R>(&route.to_path()))
+            .map_or_else(
+                || (None, PathParams::new()),
+                |(template, params)| (Some(template), params)
+            );
+        Self {
+            route,
+            path: path.to_string(),
+            route_path: route_path.to_string(),
+            query: QueryParams::parse(query),
+            fragment,
+            template,
+            params,
+            is_not_found,
+            location: None
+        }
+    }
+
+    /// Returns the history state pushed with the current entry, when it is
+    /// a `T`.
+    ///
+    /// ```rust,ignore
+    /// navigator.push_with_state(&Route::Checkout, CartId(7));
+    /// // on the checkout page:
+    /// let cart = use_route_info::<Route>().state::<CartId>();
+    /// ```
+    #[must_use]
+    pub fn state<T>(&self) -> Option<Rc<T>>
+    where
+        T: 'static
+    {
+        self.location.as_ref().and_then(Location::state)
+    }
+}
+
+/// Returns the current route and URL details as a [`RouteInfo`].
+///
+/// ```rust,ignore
+/// let info = use_route_info::<Route>();
+/// if info.is_not_found {
+///     return html! { <NotFound path={info.route_path.clone()} /> };
+/// }
+/// let page = info.query.get("page").unwrap_or("1");
+/// ```
 #[hook]
-pub fn use_route_info<R: Routable + 'static>() -> Option<R> {
-    use_route::<R>()
+pub fn use_route_info<R: Routable + 'static>() -> RouteInfo<R> {
+    let route = use_route::<R>();
+    let location = use_location();
+    let basename = use_basename();
+    RouteInfo::new(route, location, basename.as_deref())
 }
 
 #[cfg(test)]
 mod tests {
+    use yew_router::history::{History, MemoryHistory};
+
     use super::*;
 
     #[derive(Clone, PartialEq, Debug, Routable)]
@@ -24,24 +145,28 @@ mod tests {
         About
     }
 
-    #[test]
-    fn use_route_info_returns_option() {
-        let _ = use_route_info::<TestRoute>();
+    #[derive(Clone, PartialEq, Debug, Routable)]
+    enum NestedRoute {
+        #[at("/")]
+        Home,
+        #[at("/users")]
+        Users,
+        #[at("/users/:id")]
+        User { id: String },
+        #[not_found]
+        #[at("/404")]
+        NotFound
     }
 
-    #[test]
-    fn use_route_info_with_nested_routes() {
-        #[derive(Clone, PartialEq, Debug, Routable)]
-        enum NestedRoute {
-            #[at("/")]
-            Home,
-            #[at("/users")]
-            Users,
-            #[at("/users/:id")]
-            User { id: String }
-        }
+    fn location(path: &str) -> Location {
+        let history = MemoryHistory::new();
+        history.push_with_state(path, 7_u32);
+        history.location()
+    }
 
-        let _ = use_route_info::<NestedRoute>();
+    #[test]
+    fn use_route_info_returns_route_info() {
+        let _ = use_route_info::<TestRoute>();
     }
 
     #[test]
@@ -52,31 +177,62 @@ mod tests {
     }
 
     #[test]
-    fn use_route_info_with_complex_routes() {
-        #[derive(Clone, PartialEq, Debug, Routable)]
-        enum ComplexRoute {
-            #[at("/")]
-            Home,
-            #[at("/about")]
-            About,
-            #[at("/users/:id")]
-            User { id: String }
-        }
+    fn test_route_with_route_params() {
+        let user_route = NestedRoute::User {
+            id: "123".to_string()
+        };
+        assert_eq!(user_route.to_path(), "/users/123");
+    }
 
-        let _ = use_route_info::<ComplexRoute>();
+    #[test]
+    fn route_info_collects_url_parts() {
+        let route = NestedRoute::User {
+            id: "7".to_string()
+        };
+        let info = RouteInfo::from_url(
+            Some(route.clone()),
+            "/app/users/7",
+            "?tab=posts",
+            "#top",
+            Some("/app")
+        );
+        assert_eq!(info.route, Some(route));
+        assert_eq!(info.path, "/app/users/7");
+        assert_eq!(info.route_path, "/users/7");
+        assert_eq!(info.query.get("tab"), Some("posts"));
+        assert_eq!(info.fragment.as_deref(), Some("top"));
+        assert_eq!(info.template, Some("/users/:id"));
+        assert_eq!(info.params.get("id"), Some("7"));
+        assert!(!info.is_not_found);
     }
 
     #[test]
-    fn test_route_with_route_params() {
-        #[derive(Clone, PartialEq, Debug, Routable)]
-        enum RouteWithParams {
-            #[at("/users/:id")]
-            User { id: String }
-        }
+    fn route_info_exposes_history_state() {
+        let info = RouteInfo::new(Some(NestedRoute::Users), Some(location("/users")), None);
+        assert_eq!(info.route_path, "/users");
+        assert_eq!(info.template, Some("/users"));
+        assert_eq!(info.state::<u32>().as_deref(), Some(&7));
+        assert_eq!(info.state::<String>(), None);
+    }
 
-        let user_route = RouteWithParams::User {
-            id: "123".to_string()
-        };
-        assert_eq!(user_route.to_path(), "/users/123");
+    #[test]
+    fn route_info_flags_not_found_route() {
+        let info = RouteInfo::from_url(Some(NestedRoute::NotFound), "/missing", "", "", None);
+        assert!(info.is_not_found);
+        assert_eq!(info.route_path, "/missing");
+        assert_eq!(info.fragment, None);
+        assert_eq!(info.template, None);
+        assert!(info.params.is_empty());
+    }
+
+    #[test]
+    fn route_info_without_router_is_empty() {
+        let info = RouteInfo::<TestRoute>::new(None, None, None);
+        assert_eq!(info.route, None);
+        assert_eq!(info.path, "");
+        assert!(info.query.is_empty());
+        assert_eq!(info.template, None);
+        assert!(info.is_not_found);
+        assert_eq!(info.state::<u32>(), None);
     }
 }
@@ -0,0 +1,371 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Route-aware breadcrumb labels.
+//!
+//! A [`RouteLabelProvider<R>`] receives a [`BreadcrumbContext<R>`] for every
+//! crumb: the recognized route, the decoded segment and path, the depth, and
+//! the active locale. Provide one through a [`RouteLabelProviderContext<R>`];
+//! [`use_breadcrumbs::<R>`](super::use_breadcrumbs) consults it before the
+//! path-based [`BreadcrumbLabelProvider`].
+//!
+//! Simple apps can use [`MapLabelProvider`] (a path → label table, with
+//! per-locale overrides) or wrap a closure in [`FnLabelProvider`] instead of
+//! writing a trait impl.
+
+use std::{collections::HashMap, fmt, marker::PhantomData, rc::Rc};
+
+use yew::prelude::*;
+
+use super::breadcrumbs::BreadcrumbLabelProvider;
+
+/// Everything known about one crumb when its label is chosen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BreadcrumbContext<R> {
+    /// The route whose path is exactly this crumb's path, or `None` for a
+    /// prefix that no route of `R` matches.
+    pub route:   Option<R>,
+    /// Percent-decoded path of the crumb, e.g. `/users/hello world`.
+    pub path:    String,
+    /// Percent-decoded last segment of `path`; empty for the root crumb.
+    pub segment: String,
+    /// Position of the crumb in the trail, `0` for the root crumb, the same
+    /// as [`BreadcrumbItem::depth`](super::BreadcrumbItem::depth). Crumbs
+    /// hidden by a [`BreadcrumbConfig`](super::BreadcrumbConfig) are not
+    /// counted; without hide rules this is the number of segments in
+    /// `path`.
+    pub depth:   usize,
+    /// Locale set with [`RouteLabelProviderContext::with_locale`].
+    pub locale:  Option<AttrValue>
+}
+
+/// A breadcrumb label provider that sees the recognized route and the
+/// position of each crumb.
+///
+/// Returning `None` defers to the [`BreadcrumbLabelProvider`] in scope, or
+/// to the decoded path when there is none.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::{BreadcrumbContext, RouteLabelProvider};
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Debug, Routable)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+///     #[at("/users/:id")]
+///     User { id: u32 }
+/// }
+///
+/// struct Labels;
+///
+/// impl RouteLabelProvider<Route> for Labels {
+///     fn label(&self, crumb: &BreadcrumbContext<Route>) -> Option<String> {
+///         match crumb.route.as_ref()? {
+///             Route::Home => Some("Home".into()),
+///             Route::User {
+///                 id
+///             } => Some(format!("User #{id}"))
+///         }
+///     }
+/// }
+/// ```
+pub trait RouteLabelProvider<R> {
+    /// Returns the label of the crumb described by `crumb`, or `None` to
+    /// keep the path-based label.
+    fn label(&self, crumb: &BreadcrumbContext<R>) -> Option<String>;
+
+    /// Returns an icon for the crumb, e.g. an icon name, copied into
+    /// [`BreadcrumbItem::icon`](super::BreadcrumbItem::icon). Defaults to
+    /// `None`.
+    fn icon(&self, _crumb: &BreadcrumbContext<R>) -> Option<AttrValue> {
+        None
+    }
+}
+
+/// Yew context wrapper around a [`RouteLabelProvider<R>`] and the locale
+/// passed to it.
+///
+/// Equality is pointer-equality on the provider plus the locale, so
+/// switching the locale re-renders every breadcrumb consumer.
+pub struct RouteLabelProviderContext<R> {
+    provider: Rc<dyn RouteLabelProvider<R>>,
+    locale:   Option<AttrValue>
+}
+
+impl<R> RouteLabelProviderContext<R> {
+    /// Wraps the given provider so it can be passed to `ContextProvider`.
+    #[must_use]
+    pub fn new(provider: Rc<dyn RouteLabelProvider<R>>) -> Self {
+        Self {
+            provider,
+            locale: None
+        }
+    }
+
+    /// Sets the locale (e.g. `"de"`) handed to the provider in
+    /// [`BreadcrumbContext::locale`].
+    #[must_use]
+    pub fn with_locale(self, locale: impl Into<AttrValue>) -> Self {
+        Self {
+            locale: Some(locale.into()),
+            ..self
+        }
+    }
+
+    /// Returns a clone of the inner [`Rc`].
+    #[must_use]
+    pub fn provider(&self) -> Rc<dyn RouteLabelProvider<R>> {
+        Rc::clone(&self.provider)
+    }
+
+    /// Returns the locale, if one was set.
+    #[must_use]
+    pub const fn locale(&self) -> Option<&AttrValue> {
+        self.locale.as_ref()
+    }
+}
+
+impl<R> Clone for RouteLabelProviderContext<R> {
+    fn clone(&self) -> Self {
+        Self {
+            provider: Rc::clone(&self.provider),
+            locale:   self.locale.clone()
+        }
+    }
+}
+
+impl<R> PartialEq for RouteLabelProviderContext<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.provider, &other.provider) && self.locale == other.locale
+    }
+}
+
+impl<R> fmt::Debug for RouteLabelProviderContext<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RouteLabelProviderContext")
+            .field("locale", &self.locale)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Labels looked up by decoded path, with optional per-locale tables.
+///
+/// Usable both as a [`RouteLabelProvider<R>`] for any `R` and as a
+/// path-based [`BreadcrumbLabelProvider`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::{BreadcrumbLabelProvider, MapLabelProvider};
+///
+/// let labels = MapLabelProvider::from_iter([("/", "Home"), ("/docs", "Docs")])
+///     .with_locale("de", [("/", "Start"), ("/docs", "Doku")]);
+///
+/// assert_eq!(labels.label_for_path("/docs"), "Docs");
+/// assert_eq!(labels.lookup("/docs", Some("de")), Some("Doku"));
+/// assert_eq!(labels.lookup("/about", Some("de")), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MapLabelProvider {
+    labels:  HashMap<String, String>,
+    locales: HashMap<String, HashMap<String, String>>
+}
+
+impl MapLabelProvider {
+    /// Creates a provider from a path → label table.
+    #[must_use]
+    pub fn new(labels: HashMap<String, String>) -> Self {
+        Self {
+            labels,
+            locales: HashMap::new()
+        }
+    }
+
+    /// Adds (or extends) the table used when the locale is `locale`. Paths
+    /// missing from it fall back to the locale-independent table.
+    #[must_use]
+    pub fn with_locale<K, V>(
+        mut self,
+        locale: &str,
+        labels: impl IntoIterator<Item = (K, V)>
+    ) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>
+    {
+        self.locales
+            .entry(locale.to_string())
+            .or_default()
+            .extend(labels.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Returns the label of the decoded `path` in `locale`, or `None` when
+    /// neither table has it.
+    #[must_use]
+    pub fn lookup(&self, path: &str, locale: Option<&str>) -> Option<&str> {
+        locale
+            .and_then(|locale| self.locales.get(locale))
+            .and_then(|labels| labels.get(path))
+            .or_else(|| self.labels.get(path))
+            .map(String::as_str)
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for MapLabelProvider
+where
+    K: Into<String>,
+    V: Into<String>
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self::new(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect()
+        )
+    }
+}
+
+impl<R> RouteLabelProvider<R> for MapLabelProvider {
+    fn label(&self, crumb: &BreadcrumbContext<R>) -> Option<String> {
+        self.lookup(&crumb.path, crumb.locale.as_deref())
+            .map(ToString::to_string)
+    }
+}
+
+impl BreadcrumbLabelProvider for MapLabelProvider {
+    fn label_for_path(&self, path: &str) -> String {
+        self.lookup(path, None).unwrap_or(path).to_string()
+    }
+}
+
+/// Adapts a closure into a [`RouteLabelProvider<R>`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::rc::Rc;
+///
+/// use yew_nav_link::{BreadcrumbContext, FnLabelProvider, RouteLabelProviderContext};
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Debug, Routable)]
+/// enum Route {
+///     #[at("/")]
+///     Home
+/// }
+///
+/// let labels = FnLabelProvider::new(|crumb: &BreadcrumbContext<Route>| {
+///     (crumb.depth == 0).then(|| "Home".to_string())
+/// });
+/// let context = RouteLabelProviderContext::new(Rc::new(labels));
+/// ```
+pub struct FnLabelProvider<R, F> {
+    label:  F,
+    marker: PhantomData<fn(&R)>
+}
+
+impl<R, F> FnLabelProvider<R, F>
+where
+    F: Fn(&BreadcrumbContext<R>) -> Option<String>
+{
+    /// Wraps `label`, which returns `None` to keep the path-based label.
+    #[must_use]
+    pub const fn new(label: F) -> Self {
+        Self {
+            label,
+            marker: PhantomData
+        }
+    }
+}
+
+impl<R, F> RouteLabelProvider<R> for FnLabelProvider<R, F>
+where
+    F: Fn(&BreadcrumbContext<R>) -> Option<String>
+{
+    fn label(&self, crumb: &BreadcrumbContext<R>) -> Option<String> {
+        (self.label)(crumb)
+    }
+}
+
+impl<R, F> fmt::Debug for FnLabelProvider<R, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnLabelProvider").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crumb(path: &str, locale: Option<&'static str>) -> BreadcrumbContext<()> {
+        BreadcrumbContext {
+            route:   None,
+            path:    path.to_string(),
+            segment: path.rsplit('/').next().unwrap_or_default().to_string(),
+            depth:   path.split('/').filter(|s| !s.is_empty()).count(),
+            locale:  locale.map(AttrValue::Static)
+        }
+    }
+
+    #[test]
+    fn map_provider_prefers_locale_table() {
+        let labels = MapLabelProvider::from_iter([("/", "Home"), ("/docs", "Docs")])
+            .with_locale("de", [("/docs", "Doku")]);
+        assert_eq!(
+            labels.label(&crumb("/docs", Some("de"))),
+            Some("Doku".into())
+        );
+        assert_eq!(labels.label(&crumb("/", Some("de"))), Some("Home".into()));
+        assert_eq!(
+            labels.label(&crumb("/docs", Some("fr"))),
+            Some("Docs".into())
+        );
+        assert_eq!(labels.label(&crumb("/docs", None)), Some("Docs".into()));
+        assert_eq!(labels.label(&crumb("/about", None)), None);
+    }
+
+    #[test]
+    fn map_provider_with_locale_extends_existing_table() {
+        let labels = MapLabelProvider::default()
+            .with_locale("de", [("/", "Start")])
+            .with_locale("de", [("/docs", "Doku")]);
+        assert_eq!(labels.lookup("/", Some("de")), Some("Start"));
+        assert_eq!(labels.lookup("/docs", Some("de")), Some("Doku"));
+    }
+
+    #[test]
+    fn map_provider_as_path_provider_falls_back_to_path() {
+        let labels = MapLabelProvider::from_iter([("/docs", "Docs")]);
+        assert_eq!(labels.label_for_path("/docs"), "Docs");
+        assert_eq!(labels.label_for_path("/about"), "/about");
+    }
+
+    #[test]
+    fn fn_provider_sees_segment_and_depth() {
+        let labels = FnLabelProvider::new(|crumb: &BreadcrumbContext<()>| {
+            Some(format!("{}@{}", crumb.segment, crumb.depth))
+        });
+        assert_eq!(
+            labels.label(&crumb("/users/hello world", None)),
+            Some("hello world@2".into())
+        );
+    }
+
+    #[test]
+    fn context_eq_tracks_provider_and_locale() {
+        let provider: Rc<dyn RouteLabelProvider<()>> = Rc::new(MapLabelProvider::default());
+        let ctx = RouteLabelProviderContext::new(Rc::clone(&provider));
+        assert_eq!(ctx, ctx.clone());
+        assert_ne!(ctx, ctx.clone().with_locale("de"));
+        assert_ne!(
+            ctx,
+            RouteLabelProviderContext::new(Rc::new(MapLabelProvider::default()))
+        );
+        let localized = ctx.with_locale("de");
+        assert_eq!(localized.locale().map(AttrValue::as_str), Some("de"));
+    }
+}
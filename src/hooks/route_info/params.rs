@@ -0,0 +1,248 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Typed access to the `:name` parameters of the current route.
+//!
+//! `Routable` enums carry parameters as variant fields, so reading `id` out
+//! of `Route::User { id }` takes a `match` in every component that needs
+//! it. [`use_route_param`] instead matches the current route's path against
+//! the `#[at]` templates of `R` with [`PathPattern`] and parses the named
+//! parameter through [`FromStr`].
+
+use std::{fmt::Display, str::FromStr};
+
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::{
+    errors::{NavError, NavResult},
+    utils::{PathParams, PathPattern, path_segments}
+};
+
+/// Returns the parameter `name` of the current route, parsed as `T`.
+///
+/// ```rust,ignore
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/users/:id")]
+///     User { id: u64 },
+/// }
+///
+/// #[component]
+/// fn UserPage() -> Html {
+///     match use_route_param::<Route, u64>("id") {
+///         Ok(id) => html! { <h1>{ format!("User {id}") }</h1> },
+///         Err(err) => html! { <p>{ err.to_string() }</p> },
+///     }
+/// }
+/// ```
+///
+/// # Errors
+///
+/// See [`route_param`]; additionally [`NavError::RouteNotFound`] when no
+/// route of `R` matches the current URL.
+#[hook]
+pub fn use_route_param<R, T>(name: &str) -> NavResult<T>
+where
+    R: Routable + 'static,
+    T: FromStr,
+    T::Err: Display
+{
+    let route = use_route::<R>();
+    route.map_or_else(
+        || Err(NavError::route_not_found()),
+        |route| route_param(&route, name)
+    )
+}
+
+/// Returns the parameter `name` of `route`, parsed as `T`.
+///
+/// The route's path is matched against every `#[at]` template of `R`; when
+/// several match (`/users/new` and `/users/:id`), the one with the most
+/// literal segments from the left wins, as in the router itself. Values are
+/// percent-decoded before parsing.
+///
+/// # Errors
+///
+/// Returns [`NavError::InvalidRoute`] when the matching template has no
+/// parameter `name`, or when its value does not parse as `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::{NavError, route_param};
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/users/:id")]
+///     User { id: String }
+/// }
+///
+/// let route = Route::User {
+///     id: "42".to_string()
+/// };
+/// assert_eq!(route_param::<_, u64>(&route, "id"), Ok(42));
+/// assert!(matches!(
+///     route_param::<_, u64>(&route, "page"),
+///     Err(NavError::InvalidRoute(_))
+/// ));
+/// ```
+pub fn route_param<R, T>(route: &R, name: &str) -> NavResult<T>
+where
+    R: Routable,
+    T: FromStr,
+    T::Err: Display
+{
+    let path = route.to_path();
+    let (_, params) = matched_template::<R>(&path)
+        .ok_or_else(|| NavError::invalid_route(format!("no template of `{path}` matches")))?;
+    let value = params
+        .get(name)
+        .ok_or_else(|| NavError::invalid_route(format!("`{path}` has no parameter `{name}`")))?;
+    value
+        .parse()
+        .map_err(|err| NavError::invalid_route(format!("parameter `{name}` = `{value}`: {err}")))
+}
+
+/// Matches `path` against the `#[at]` templates of `R`, returning the most
+/// specific matching template and its parameters.
+pub(super) fn matched_template<R>(path: &str) -> Option<(&'static str, PathParams)>
+where
+    R: Routable
+{
+    R::routes()
+        .into_iter()
+        .filter_map(|template| {
+            let params = PathPattern::parse(template).ok()?.matches(path)?;
+            Some((specificity(template), template, params))
+        })
+        .fold(
+            None,
+            |best: Option<(Vec<u8>, &'static str, PathParams)>, candidate| match best {
+                Some(best) if best.0 >= candidate.0 => Some(best),
+                _ => Some(candidate)
+            }
+        )
+        .map(|(_, template, params)| (template, params))
+}
+
+/// Ranks each template segment: literal over `:param` over `:optional?`
+/// over `*wildcard`.
+fn specificity(template: &str) -> Vec<u8> {
+    path_segments(template)
+        .map(|segment| {
+            if segment.starts_with('*') {
+                0
+            } else if segment.starts_with(':') {
+                if segment.ends_with('?') { 1 } else { 2 }
+            } else {
+                3
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq, Debug, Routable)]
+    enum TestRoute {
+        #[at("/")]
+        Home,
+        #[at("/users/:id")]
+        User { id: String },
+        #[at("/users/new")]
+        NewUser,
+        #[at("/users/:id/posts/:slug")]
+        Post { id: String, slug: String },
+        #[at("/files/*path")]
+        File { path: String }
+    }
+
+    #[test]
+    fn parses_typed_param() {
+        let route = TestRoute::User {
+            id: "42".to_string()
+        };
+        assert_eq!(route_param::<_, u64>(&route, "id"), Ok(42));
+    }
+
+    #[test]
+    fn reads_several_params() {
+        let route = TestRoute::Post {
+            id:   "7".to_string(),
+            slug: "hello".to_string()
+        };
+        assert_eq!(route_param::<_, u32>(&route, "id"), Ok(7));
+        assert_eq!(
+            route_param::<_, String>(&route, "slug"),
+            Ok("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn decodes_before_parsing() {
+        let route = TestRoute::User {
+            id: "jürgen".to_string()
+        };
+        assert_eq!(
+            route_param::<_, String>(&route, "id"),
+            Ok("jürgen".to_string())
+        );
+        let route = TestRoute::File {
+            path: "a/b c".to_string()
+        };
+        assert_eq!(
+            route_param::<_, String>(&route, "path"),
+            Ok("a/b c".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_error_is_invalid_route() {
+        let route = TestRoute::User {
+            id: "abc".to_string()
+        };
+        let err = route_param::<_, u64>(&route, "id").unwrap_err();
+        assert!(matches!(&err, NavError::InvalidRoute(msg) if msg.contains("`id` = `abc`")));
+    }
+
+    #[test]
+    fn missing_param_is_invalid_route() {
+        assert!(matches!(
+            route_param::<_, String>(&TestRoute::Home, "id"),
+            Err(NavError::InvalidRoute(_))
+        ));
+    }
+
+    #[test]
+    fn literal_template_beats_param_template() {
+        assert!(route_param::<_, String>(&TestRoute::NewUser, "id").is_err());
+    }
+
+    #[test]
+    fn matched_template_reports_the_template() {
+        assert_eq!(
+            matched_template::<TestRoute>("/users/new").map(|(t, _)| t),
+            Some("/users/new")
+        );
+        assert_eq!(
+            matched_template::<TestRoute>("/users/7").map(|(t, _)| t),
+            Some("/users/:id")
+        );
+        assert_eq!(matched_template::<TestRoute>("/nope"), None);
+    }
+
+    #[test]
+    fn specificity_ranks_segments() {
+        assert_eq!(specificity("/a/:b/:c?/*d"), [3, 2, 1, 0]);
+        assert!(specificity("/users/new") > specificity("/users/:id"));
+    }
+
+    #[test]
+    fn use_route_param_is_callable() {
+        let _ = use_route_param::<TestRoute, u64>("id");
+    }
+}
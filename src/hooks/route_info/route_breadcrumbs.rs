@@ -0,0 +1,325 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Breadcrumbs from an explicit route hierarchy.
+//!
+//! [`use_breadcrumbs`](super::use_breadcrumbs) derives the trail from the
+//! URL string, so every path prefix becomes a crumb. Routes implementing
+//! [`RouteParent`] declare their parent instead, and
+//! [`use_route_breadcrumbs`] walks that chain: `/users/42` can sit under a
+//! `Users` list at `/people` without a bogus `/users` crumb.
+
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use super::breadcrumbs::{BreadcrumbItem, display_path};
+use crate::utils::{path_segments, with_basename};
+
+/// A route that knows its place in the breadcrumb hierarchy.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::{BreadcrumbTree, RouteParent};
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Debug, Routable)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+///     #[at("/people")]
+///     Users,
+///     #[at("/users/:id")]
+///     User { id: u32 }
+/// }
+///
+/// impl RouteParent for Route {
+///     fn parent(&self) -> Option<Self> {
+///         match self {
+///             Self::Home => None,
+///             Self::Users => Some(Self::Home),
+///             Self::User {
+///                 ..
+///             } => Some(Self::Users)
+///         }
+///     }
+///
+///     fn label(&self) -> String {
+///         match self {
+///             Self::Home => "Home".into(),
+///             Self::Users => "Users".into(),
+///             Self::User {
+///                 id
+///             } => format!("User #{id}")
+///         }
+///     }
+/// }
+///
+/// let tree = BreadcrumbTree::new(Route::User {
+///     id: 42
+/// });
+/// assert_eq!(
+///     tree.routes(),
+///     [
+///         Route::Home,
+///         Route::Users,
+///         Route::User {
+///             id: 42
+///         }
+///     ]
+/// );
+/// ```
+pub trait RouteParent: Routable + Clone + PartialEq {
+    /// Returns the route one level up, or `None` for a top-level route.
+    fn parent(&self) -> Option<Self>;
+
+    /// Returns the breadcrumb label of this route.
+    ///
+    /// Defaults to the percent-decoded route path, matching the default
+    /// labels of [`use_breadcrumbs`](super::use_breadcrumbs).
+    fn label(&self) -> String {
+        display_path(&self.to_path()).into_owned()
+    }
+}
+
+/// The chain of routes from a top-level ancestor down to one route, built
+/// by following [`RouteParent::parent`].
+///
+/// The walk stops at the first repeated route, so a cyclic `parent`
+/// implementation yields a finite trail instead of hanging.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BreadcrumbTree<R> {
+    routes: Vec<R>
+}
+
+impl<R> BreadcrumbTree<R>
+where
+    R: RouteParent
+{
+    /// Builds the trail ending at `route`.
+    #[must_use]
+    pub fn new(route: R) -> Self {
+        let mut routes = vec![route];
+        while let Some(parent) = routes.last().and_then(RouteParent::parent) {
+            if routes.contains(&parent) {
+                break;
+            }
+            routes.push(parent);
+        }
+        routes.reverse();
+        Self {
+            routes
+        }
+    }
+
+    /// Returns the routes, top-level ancestor first and the current route
+    /// last. Never empty.
+    #[must_use]
+    pub fn routes(&self) -> &[R] {
+        &self.routes
+    }
+
+    /// Converts the trail into [`BreadcrumbItem`]s whose hrefs are prefixed
+    /// with `basename`. Only the last item is active.
+    #[must_use]
+    pub fn items(&self, basename: &str) -> Vec<BreadcrumbItem<R>> {
+        let last = self.routes.len() - 1;
+        self.routes
+            .iter()
+            .enumerate()
+            .map(|(i, route)| {
+                let path = route.to_path();
+                BreadcrumbItem {
+                    route: route.clone(),
+                    href: with_basename(&path, basename),
+                    segment: path_segments(&path)
+                        .last()
+                        .map(|segment| display_path(segment).into_owned())
+                        .unwrap_or_default(),
+                    path,
+                    label: route.label(),
+                    icon: None,
+                    depth: i,
+                    is_recognized: true,
+                    is_active: i == last
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the breadcrumb trail of the current route, walked through its
+/// [`RouteParent`] chain rather than derived from the URL.
+///
+/// Labels come from [`RouteParent::label`]; hrefs are prefixed with the
+/// router basename. Returns an empty `Vec` when no route matches.
+///
+/// ```rust,ignore
+/// use yew::prelude::*;
+/// use yew_nav_link::use_route_breadcrumbs;
+/// use yew_router::prelude::*;
+///
+/// #[component]
+/// fn Crumbs() -> Html {
+///     let trail = use_route_breadcrumbs::<Route>();
+///
+///     html! {
+///         <ol>
+///             { for trail.into_iter().map(|item| html! {
+///                 <li><Link<Route> to={item.route}>{ item.label }</Link<Route>></li>
+///             }) }
+///         </ol>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_route_breadcrumbs<R>() -> Vec<BreadcrumbItem<R>>
+where
+    R: RouteParent + 'static
+{
+    let current = use_route::<R>();
+    let navigator = use_navigator();
+    let basename = navigator
+        .as_ref()
+        .and_then(Navigator::basename)
+        .unwrap_or_default();
+
+    current.map_or_else(Vec::new, |route| BreadcrumbTree::new(route).items(basename))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, PartialEq, Debug, Routable)]
+    enum Route {
+        #[at("/")]
+        Home,
+        #[at("/people")]
+        Users,
+        #[at("/users/:id")]
+        User { id: String },
+        #[at("/users/:id/settings")]
+        Settings { id: String },
+        #[at("/orphan")]
+        Orphan
+    }
+
+    impl RouteParent for Route {
+        fn parent(&self) -> Option<Self> {
+            match self {
+                Self::Home | Self::Orphan => None,
+                Self::Users => Some(Self::Home),
+                Self::User {
+                    ..
+                } => Some(Self::Users),
+                Self::Settings {
+                    id
+                } => Some(Self::User {
+                    id: id.clone()
+                })
+            }
+        }
+
+        fn label(&self) -> String {
+            match self {
+                Self::Home => "Home".into(),
+                Self::Users => "Users".into(),
+                Self::User {
+                    id
+                } => format!("User {id}"),
+                Self::Settings {
+                    ..
+                } => "Settings".into(),
+                Self::Orphan => "Orphan".into()
+            }
+        }
+    }
+
+    #[derive(Clone, PartialEq, Debug, Routable)]
+    enum Cyclic {
+        #[at("/a")]
+        A,
+        #[at("/b")]
+        B
+    }
+
+    impl RouteParent for Cyclic {
+        fn parent(&self) -> Option<Self> {
+            Some(match self {
+                Self::A => Self::B,
+                Self::B => Self::A
+            })
+        }
+    }
+
+    fn settings() -> Route {
+        Route::Settings {
+            id: "42".into()
+        }
+    }
+
+    #[test]
+    fn tree_walks_declared_parents_not_url_prefixes() {
+        let tree = BreadcrumbTree::new(settings());
+        assert_eq!(
+            tree.routes(),
+            [
+                Route::Home,
+                Route::Users,
+                Route::User {
+                    id: "42".into()
+                },
+                settings()
+            ]
+        );
+    }
+
+    #[test]
+    fn tree_of_top_level_route_is_itself() {
+        assert_eq!(BreadcrumbTree::new(Route::Orphan).routes(), [Route::Orphan]);
+    }
+
+    #[test]
+    fn tree_stops_at_a_cycle() {
+        assert_eq!(
+            BreadcrumbTree::new(Cyclic::A).routes(),
+            [Cyclic::B, Cyclic::A]
+        );
+    }
+
+    #[test]
+    fn items_use_declared_labels_and_mark_last_active() {
+        let items = BreadcrumbTree::new(settings()).items("");
+        let labels: Vec<_> = items.iter().map(|item| item.label.as_str()).collect();
+        assert_eq!(labels, ["Home", "Users", "User 42", "Settings"]);
+        let active: Vec<_> = items.iter().map(|item| item.is_active).collect();
+        assert_eq!(active, [false, false, false, true]);
+    }
+
+    #[test]
+    fn items_prefix_hrefs_with_basename() {
+        let items = BreadcrumbTree::new(Route::Users).items("/app/v2");
+        let hrefs: Vec<_> = items.iter().map(|item| item.href.as_str()).collect();
+        assert_eq!(hrefs, ["/app/v2/", "/app/v2/people"]);
+    }
+
+    #[test]
+    fn items_carry_path_segment_and_depth() {
+        let items = BreadcrumbTree::new(Route::User {
+            id: "hello world".into()
+        })
+        .items("");
+        let user = items.last().unwrap();
+        assert_eq!(user.path, "/users/hello%20world");
+        assert_eq!(user.segment, "hello world");
+        assert_eq!(user.depth, 2);
+        assert!(user.is_recognized);
+        assert_eq!(items[0].segment, "");
+    }
+
+    #[test]
+    fn default_label_is_decoded_path() {
+        assert_eq!(Cyclic::A.label(), "/a");
+    }
+}
@@ -0,0 +1,115 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! # Hydration
+//!
+//! Available with the `hydration` feature. [`HydrationRouter`] is the client
+//! counterpart of [`StaticRouter`](crate::ssr::StaticRouter): it reads the
+//! browser's location like `BrowserRouter`, but wraps `Router` in the same
+//! number of component layers as `StaticRouter`, so markup rendered on the
+//! server lines up with the tree [`yew::Renderer::hydrate`] walks.
+//! `BrowserRouter` nests one layer less and cannot hydrate it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_nav_link::{HydrationRouter, NavLink};
+//! use yew_router::prelude::*;
+//!
+//! #[derive(Clone, PartialEq, Debug, Routable)]
+//! enum Route {
+//!     #[at("/")]
+//!     Home,
+//!     #[at("/docs")]
+//!     Docs
+//! }
+//!
+//! #[component]
+//! fn App() -> Html {
+//!     html! {
+//!         <HydrationRouter>
+//!             <NavLink<Route> to={Route::Home}>{ "Home" }</NavLink<Route>>
+//!             <NavLink<Route> to={Route::Docs}>{ "Docs" }</NavLink<Route>>
+//!         </HydrationRouter>
+//!     }
+//! }
+//!
+//! // In the browser, over the markup the server sent:
+//! // yew::Renderer::<App>::new().hydrate();
+//! ```
+//!
+//! # Render Is DOM-Free
+//!
+//! The first client render has to produce exactly the server's markup, so
+//! no component reads the browser while rendering. Everything that touches
+//! the DOM runs once mounted:
+//!
+//! - [`NavDropdown`](crate::NavDropdown) starts closed on both sides; its open
+//!   state changes only in event handlers, and moving focus into the opened
+//!   menu is an effect.
+//! - [`NavTabs`](crate::NavTabs) and [`NavDropdown`](crate::NavDropdown) query
+//!   focusable elements and `document.activeElement` from keyboard handlers
+//!   only; their `NodeRef`s are read after mount.
+//! - [`NavGroup`](crate::NavGroup) registration, async breadcrumb labels, and
+//!   the history and event providers settle in effects, after hydration. The
+//!   first client render sees only a group's declared `routes`, as the server
+//!   did.
+
+use yew::prelude::*;
+use yew_router::{
+    history::{AnyHistory, BrowserHistory},
+    prelude::*
+};
+
+/// Properties for the [`HydrationRouter`] component.
+///
+/// | Prop | Type | Default | Description |
+/// |------|------|---------|-------------|
+/// | `basename` | `Option<AttrValue>` | `None` | Router basename, as for `StaticRouter` |
+/// | `children` | `Html` | — | The application |
+#[derive(Properties, Clone, PartialEq, Debug)]
+pub struct HydrationRouterProps {
+    /// Router basename (e.g. `/app`); must match the one the server
+    /// rendered with.
+    #[prop_or_default]
+    pub basename: Option<AttrValue>,
+
+    /// The application rendered under the router.
+    #[prop_or_default]
+    pub children: Html
+}
+
+/// A browser-history router whose component tree matches
+/// [`StaticRouter`](crate::ssr::StaticRouter), for hydrating server-rendered
+/// markup.
+#[component]
+pub fn HydrationRouter(props: &HydrationRouterProps) -> Html {
+    let history = use_state(|| AnyHistory::from(BrowserHistory::new()));
+    html! {
+        <Router history={(*history).clone()} basename={props.basename.clone()}>
+            { props.children.clone() }
+        </Router>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hydration_router_props_equality() {
+        let props = HydrationRouterProps {
+            basename: Some("/app".into()),
+            children: Html::default()
+        };
+        assert_eq!(props.clone(), props);
+        assert_ne!(
+            props,
+            HydrationRouterProps {
+                basename: None,
+                children: Html::default()
+            }
+        );
+    }
+}
@@ -124,6 +124,9 @@ pub mod components;
 /// Error types returned by navigation operations.
 pub mod errors;
 
+/// Navigation event bus for analytics and logging.
+pub mod events;
+
 /// Reactive hooks for route and navigation state.
 pub mod hooks;
 
@@ -136,6 +139,14 @@ pub mod active_link;
 /// Path, URL, query string, and keyboard navigation utilities.
 pub mod utils;
 
+/// Server-side rendering support (`ssr` feature).
+#[cfg(feature = "ssr")]
+pub mod ssr;
+
+/// Hydration of server-rendered markup (`hydration` feature).
+#[cfg(feature = "hydration")]
+pub mod hydration;
+
 /// Compiles the `rust` code blocks in `README.md` as part of
 /// `cargo test --doc`, so the README's examples cannot silently drift from the
 /// public API. Only present during doctest builds.
@@ -143,19 +154,38 @@ pub mod utils;
 #[doc = include_str!("../README.md")]
 pub struct ReadmeDoctests;
 
-pub use active_link::{Match, NavLink, NavLinkProps, nav_link};
+pub use active_link::{
+    AriaCurrent, GroupRoute, LinkState, Match, NavGroup, NavGroupProps, NavLink, NavLinkProps,
+    nav_link, use_link_state
+};
 pub use components::{
-    NavBadge, NavBadgeProps, NavDropdown, NavDropdownDivider, NavDropdownItem, NavDropdownProps,
-    NavHeader, NavHeaderProps, NavIcon, NavIconProps, NavIconSize, NavLinkWithIcon,
-    NavLinkWithIconProps, NavTab, NavTabPanel, NavTabPanelProps, NavTabProps, NavTabs,
-    NavTabsProps, NavText, NavTextProps, PageItem, PageItemProps, PageLink, PageLinkProps,
-    Pagination, PaginationProps
+    BreadcrumbSchema, Breadcrumbs, BreadcrumbsProps, NavBadge, NavBadgeProps, NavDropdown,
+    NavDropdownDivider, NavDropdownItem, NavDropdownProps, NavHeader, NavHeaderProps, NavIcon,
+    NavIconProps, NavIconSize, NavLinkWithIcon, NavLinkWithIconProps, NavTab, NavTabPanel,
+    NavTabPanelProps, NavTabProps, NavTabs, NavTabsProps, NavText, NavTextProps, PageItem,
+    PageItemProps, PageLink, PageLinkProps, Pagination, PaginationProps
 };
 pub use errors::{NavError, NavResult};
+pub use events::{
+    NavEvent, NavEvents, NavEventsProvider, NavEventsProviderProps, NavigationDirection,
+    use_nav_event_listener, use_nav_events
+};
 pub use hooks::{
-    BreadcrumbItem, BreadcrumbLabelProvider, BreadcrumbLabelProviderContext, Navigation,
-    use_breadcrumbs, use_is_active, use_is_exact_active, use_is_partial_active, use_navigation,
-    use_query_params, use_route_info
+    AsyncBreadcrumbLabelProvider, BreadcrumbConfig, BreadcrumbContext, BreadcrumbItem,
+    BreadcrumbLabelProvider, BreadcrumbLabelProviderContext, BreadcrumbRule, BreadcrumbTree,
+    FnLabelProvider, HistoryEntry, LabelFuture, MapLabelProvider, Navigation,
+    NavigationHistoryProvider, NavigationHistoryProviderProps, RouteInfo, RouteLabelProvider,
+    RouteLabelProviderContext, RouteParent, route_param, use_active_index, use_active_route_among,
+    use_basename, use_breadcrumbs, use_history_stack, use_is_active, use_is_exact_active,
+    use_is_partial_active, use_navigation, use_previous_route, use_query_params,
+    use_route_breadcrumbs, use_route_info, use_route_param
 };
+#[cfg(feature = "hydration")]
+pub use hydration::{HydrationRouter, HydrationRouterProps};
 pub use nav::{NavDivider, NavDividerProps, NavItem, NavItemProps, NavList, NavListProps};
-pub use utils::{is_absolute, join_paths, normalize_path};
+#[cfg(feature = "ssr")]
+pub use ssr::{StaticRouter, StaticRouterProps};
+pub use utils::{
+    PathParams, PathPattern, common_prefix, is_absolute, join_paths, normalize_path, parent_path,
+    path_segments, relative_path, strip_base, strip_basename, with_basename
+};
@@ -0,0 +1,172 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! # Server-side rendering
+//!
+//! Available with the `ssr` feature. [`StaticRouter`] is a router fixed at
+//! one URL, the request URL on a server, so `NavLink`, the hooks, and the
+//! components render the page as the browser will show it through
+//! [`yew::ServerRenderer`]: active classes, `aria-current`, breadcrumbs, and
+//! query parameters all come from that URL.
+//!
+//! # Example
+//!
+//! ```rust
+//! use yew::prelude::*;
+//! use yew_nav_link::{NavLink, StaticRouter};
+//! use yew_router::prelude::*;
+//!
+//! #[derive(Clone, PartialEq, Debug, Routable)]
+//! enum Route {
+//!     #[at("/")]
+//!     Home,
+//!     #[at("/docs")]
+//!     Docs
+//! }
+//!
+//! #[derive(Properties, PartialEq)]
+//! struct AppProps {
+//!     url: AttrValue
+//! }
+//!
+//! #[component]
+//! fn App(props: &AppProps) -> Html {
+//!     html! {
+//!         <StaticRouter url={props.url.clone()}>
+//!             <NavLink<Route> to={Route::Home}>{ "Home" }</NavLink<Route>>
+//!             <NavLink<Route> to={Route::Docs}>{ "Docs" }</NavLink<Route>>
+//!         </StaticRouter>
+//!     }
+//! }
+//!
+//! # futures::executor::block_on(async {
+//! let html = yew::ServerRenderer::<App>::with_props(|| AppProps {
+//!     url: "/docs".into()
+//! })
+//! .hydratable(false)
+//! .render()
+//! .await;
+//! assert!(html.contains(r#"<a href="/docs" aria-current="page" class="nav-link active">"#));
+//! # });
+//! ```
+//!
+//! # What Renders on the Server
+//!
+//! Everything computed during render: active state, classes, hrefs,
+//! `aria-current`, breadcrumb trails, and route and query hooks. Effects do
+//! not run on the server, so state that is settled in an effect keeps its
+//! initial value in the emitted HTML:
+//!
+//! - Inside a [`NavGroup`](crate::NavGroup), links register with the group in
+//!   an effect, so on the server the group only knows the links declared in its
+//!   `routes` prop. List them there for exclusive active state in the emitted
+//!   HTML.
+//! - Async breadcrumb labels render their fallback label.
+//! - [`NavigationHistoryProvider`](crate::NavigationHistoryProvider) and
+//!   [`NavEventsProvider`](crate::NavEventsProvider) record nothing.
+
+use yew::prelude::*;
+use yew_router::{
+    history::{AnyHistory, History, MemoryHistory},
+    prelude::*
+};
+
+use crate::utils::{QueryParams, UrlParts};
+
+/// Properties for the [`StaticRouter`] component.
+///
+/// | Prop | Type | Default | Description |
+/// |------|------|---------|-------------|
+/// | `url` | `AttrValue` | — | URL to render at, a path or an absolute URL |
+/// | `basename` | `Option<AttrValue>` | `None` | Router basename, as for `BrowserRouter` |
+/// | `children` | `Html` | — | The application |
+#[derive(Properties, Clone, PartialEq, Debug)]
+pub struct StaticRouterProps {
+    /// URL to render at: a path with an optional query (`/docs?page=2`) or
+    /// an absolute URL. The fragment, which servers never receive, is
+    /// ignored.
+    pub url: AttrValue,
+
+    /// Router basename (e.g. `/app`). `url` is expected to carry it.
+    #[prop_or_default]
+    pub basename: Option<AttrValue>,
+
+    /// The application rendered under the router.
+    #[prop_or_default]
+    pub children: Html
+}
+
+/// A router fixed at `url`, for rendering on a server.
+///
+/// Provides the same contexts as `BrowserRouter`, backed by a
+/// [`MemoryHistory`] holding `url`, so navigation hooks work but nothing
+/// touches the browser.
+#[component]
+pub fn StaticRouter(props: &StaticRouterProps) -> Html {
+    let history = use_memo(props.url.clone(), |url| static_history(url));
+    html! {
+        <Router history={(*history).clone()} basename={props.basename.clone()}>
+            { props.children.clone() }
+        </Router>
+    }
+}
+
+/// Builds a history whose only entry is the path and query of `url`.
+fn static_history(url: &str) -> AnyHistory {
+    let parts = UrlParts::parse(url);
+    let path = if parts.path.is_empty() {
+        "/"
+    } else {
+        parts.path.as_str()
+    };
+    let history = MemoryHistory::new();
+    let query = parts
+        .query
+        .as_deref()
+        .map(QueryParams::parse)
+        .filter(|query| !query.is_empty());
+    let replaced = query.is_some_and(|query| {
+        let pairs: Vec<_> = query.iter_all().collect();
+        history.replace_with_query(path, pairs).is_ok()
+    });
+    if !replaced {
+        history.replace(path);
+    }
+    history.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_history_holds_path_and_query() {
+        let location = static_history("/docs?page=2&tag=a%20b#intro").location();
+        assert_eq!(location.path(), "/docs");
+        assert_eq!(location.query_str(), "?page=2&tag=a+b");
+        assert_eq!(location.hash(), "");
+    }
+
+    #[test]
+    fn static_history_accepts_absolute_urls() {
+        let location = static_history("https://example.com/app/docs").location();
+        assert_eq!(location.path(), "/app/docs");
+        assert_eq!(location.query_str(), "");
+    }
+
+    #[test]
+    fn static_history_defaults_to_root() {
+        assert_eq!(static_history("").location().path(), "/");
+        assert_eq!(static_history("?q=1").location().path(), "/");
+    }
+
+    #[test]
+    fn static_router_props_equality() {
+        let props = StaticRouterProps {
+            url:      "/docs".into(),
+            basename: None,
+            children: Html::default()
+        };
+        assert_eq!(props.clone(), props);
+    }
+}
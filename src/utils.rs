@@ -14,5 +14,14 @@ pub use keyboard::{
     KeyboardDirection, KeyboardNavConfig, handle_arrow_key, handle_home_end, is_activation_key,
     is_navigation_key
 };
-pub use path::{is_absolute, join_paths, normalize_path};
-pub use url::{QueryParams, UrlParts, percent_decode, urlencoding_decode, urlencoding_encode};
+pub(crate) use path::segments_eq;
+pub use path::{
+    PathParams, PathPattern, common_prefix, is_absolute, join_paths, normalize_path, parent_path,
+    path_segments, relative_path, strip_base, strip_basename, with_basename
+};
+pub use url::{
+    DecodeError, DecodeErrorKind, EncodeSet, Encoded, Host, Origin, QueryEncoding, QueryParams,
+    UrlBuilder, UrlParts, decode_cow_with, decode_with, default_port, encode_with, percent_decode,
+    percent_decode_cow, try_decode_with, try_percent_decode, urlencoding_decode,
+    urlencoding_decode_cow, urlencoding_encode
+};
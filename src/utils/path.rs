@@ -4,7 +4,7 @@
 //! # Path Utilities
 //!
 //! Helper functions for working with URL paths: normalization,
-//! absolute path detection, and path joining.
+//! absolute path detection, path joining, and path templates.
 //!
 //! # Example
 //!
@@ -24,6 +24,19 @@
 //! | `normalize_path` | `(path: &str) -> String` | Collapse `//`, resolve `.`/`..` without escaping root |
 //! | `is_absolute` | `(path: &str) -> bool` | Check if path starts with `/` |
 //! | `join_paths` | `(base: &str, path: &str) -> String` | Join or replace base path |
+//! | `path_segments` | `(path: &str) -> impl Iterator<Item = &str>` | Non-empty raw segments; `%2F` does not split |
+//! | `parent_path` | `(path: &str) -> Option<String>` | Drop the last segment |
+//! | `common_prefix` | `(a: &str, b: &str) -> String` | Longest shared segment prefix |
+//! | `strip_base` | `(path: &str, base: &str) -> Option<&str>` | Remove a segment-wise base prefix |
+//! | `relative_path` | `(from: &str, to: &str) -> String` | `../../settings` style reference from one page to another |
+//! | `PathPattern::parse` | `(template: &str) -> NavResult<PathPattern>` | Compile a `/users/:id` style template |
+
+use std::fmt;
+
+use crate::{
+    errors::{NavError, NavResult},
+    utils::{EncodeSet, decode_cow_with, decode_with, encode_with}
+};
 
 /// Normalizes a path: collapse duplicate separators and resolve `.` and
 /// `..` segments without escaping the root.
@@ -116,6 +129,525 @@ pub fn join_paths(base: &str, path: &str) -> String {
     }
 }
 
+/// Returns the non-empty segments of `path`, still percent-encoded.
+///
+/// Only a literal `/` separates segments, so an encoded `%2F` stays inside
+/// its segment; empty segments from leading, trailing, or doubled slashes
+/// are skipped. Every segment-aware helper in this module, as well as
+/// [`is_path_prefix`](crate::active_link::is_path_prefix) and
+/// [`use_breadcrumbs`](crate::hooks::use_breadcrumbs), splits paths this
+/// way.
+///
+/// # Examples
+///
+/// ```
+/// use yew_nav_link::path_segments;
+///
+/// let segments: Vec<_> = path_segments("//files/a%2Fb/").collect();
+/// assert_eq!(segments, ["files", "a%2Fb"]);
+/// ```
+#[must_use]
+pub fn path_segments(path: &str) -> impl DoubleEndedIterator<Item = &str> + Clone {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+/// Compares two raw segments, treating equivalent percent-encodings
+/// (`caf%C3%A9` and `café`, `%7E` and `~`) as equal.
+pub fn segments_eq(a: &str, b: &str) -> bool {
+    a == b
+        || matches!(
+            (
+                decode_cow_with(a, EncodeSet::PathSegment),
+                decode_cow_with(b, EncodeSet::PathSegment)
+            ),
+            (Some(a), Some(b)) if a == b
+        )
+}
+
+/// Returns the path without its last segment, or `None` when there is no
+/// segment to remove.
+///
+/// A trailing `/` is ignored and the parent of a top-level absolute path is
+/// `/`. Empty segments are collapsed.
+///
+/// # Examples
+///
+/// ```
+/// use yew_nav_link::parent_path;
+///
+/// assert_eq!(parent_path("/users/42/edit").as_deref(), Some("/users/42"));
+/// assert_eq!(parent_path("/users/").as_deref(), Some("/"));
+/// assert_eq!(parent_path("/"), None);
+/// ```
+#[must_use]
+pub fn parent_path(path: &str) -> Option<String> {
+    let mut segments = path_segments(path);
+    segments.next_back()?;
+    let parent = segments.collect::<Vec<_>>().join("/");
+    Some(if is_absolute(path) {
+        format!("/{parent}")
+    } else {
+        parent
+    })
+}
+
+/// Returns the longest segment-wise prefix shared by two paths.
+///
+/// The result is rooted when `a` is absolute, and is `/` (or `""` for a
+/// relative `a`) when the paths share no segment. Segments are compared
+/// with percent-encoding normalized, and the spelling from `a` is kept.
+///
+/// # Examples
+///
+/// ```
+/// use yew_nav_link::common_prefix;
+///
+/// assert_eq!(
+///     common_prefix("/docs/api/hooks", "/docs/api/utils"),
+///     "/docs/api"
+/// );
+/// assert_eq!(common_prefix("/docs", "/documents"), "/");
+/// ```
+#[must_use]
+pub fn common_prefix(a: &str, b: &str) -> String {
+    let shared = path_segments(a)
+        .zip(path_segments(b))
+        .take_while(|(x, y)| segments_eq(x, y))
+        .map(|(x, _)| x)
+        .collect::<Vec<_>>()
+        .join("/");
+    if is_absolute(a) {
+        format!("/{shared}")
+    } else {
+        shared
+    }
+}
+
+/// Removes `base` from the front of `path` segment by segment, returning the
+/// rooted remainder, or `None` when `path` is not under `base`.
+///
+/// `/application` is not under `/app`, and an empty or `/` base leaves the
+/// path unchanged. Segments are compared with percent-encoding normalized.
+///
+/// # Examples
+///
+/// ```
+/// use yew_nav_link::strip_base;
+///
+/// assert_eq!(strip_base("/app/docs/api", "/app"), Some("/docs/api"));
+/// assert_eq!(strip_base("/app", "/app/"), Some("/"));
+/// assert_eq!(strip_base("/application", "/app"), None);
+/// ```
+#[must_use]
+pub fn strip_base<'a>(path: &'a str, base: &str) -> Option<&'a str> {
+    let mut rest = path;
+    for base_segment in path_segments(base) {
+        let trimmed = rest.trim_start_matches('/');
+        let end = trimmed.find('/').unwrap_or(trimmed.len());
+        let (segment, after) = trimmed.split_at(end);
+        if segment.is_empty() || !segments_eq(segment, base_segment) {
+            return None;
+        }
+        rest = after;
+    }
+    Some(if rest.is_empty() { "/" } else { rest })
+}
+
+/// Prefixes a route path with the router basename, the way yew-router's
+/// `Navigator` does when it pushes a route.
+///
+/// A trailing `/` on `basename` is ignored and `path` is rooted, so
+/// `with_basename("/docs", "/app/v2/")` is `/app/v2/docs`. An empty or `/`
+/// basename returns the rooted path unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use yew_nav_link::with_basename;
+///
+/// assert_eq!(with_basename("/docs", "/app/v2"), "/app/v2/docs");
+/// assert_eq!(with_basename("/", "/app/v2/"), "/app/v2/");
+/// assert_eq!(with_basename("docs", ""), "/docs");
+/// ```
+#[must_use]
+pub fn with_basename(path: &str, basename: &str) -> String {
+    let base = basename.trim_end_matches('/');
+    let base_root = if base.is_empty() || base.starts_with('/') {
+        ""
+    } else {
+        "/"
+    };
+    let path_root = if path.starts_with('/') { "" } else { "/" };
+    format!("{base_root}{base}{path_root}{path}")
+}
+
+/// Removes the router basename from the front of a browser path, leaving the
+/// route path the router matches against.
+///
+/// Unlike [`strip_base`] this never fails: a path outside the basename is
+/// returned unchanged, which is what the router sees for such a location.
+///
+/// # Examples
+///
+/// ```
+/// use yew_nav_link::strip_basename;
+///
+/// assert_eq!(strip_basename("/app/v2/docs", "/app/v2"), "/docs");
+/// assert_eq!(strip_basename("/app/v2", "/app/v2/"), "/");
+/// assert_eq!(strip_basename("/elsewhere", "/app/v2"), "/elsewhere");
+/// ```
+#[must_use]
+pub fn strip_basename<'a>(path: &'a str, basename: &str) -> &'a str {
+    strip_base(path, basename).unwrap_or(path)
+}
+
+/// Returns a relative reference that leads from the page at `from` to the
+/// absolute path `to`, e.g. `../../settings`.
+///
+/// `from` is resolved the way a browser resolves an `href`: its last
+/// segment is the page itself, so the reference is relative to the
+/// directory containing it unless `from` ends with `/`. Feeding the result
+/// to [`UrlParts::resolve`](crate::utils::UrlParts::resolve) with `from` as
+/// the base yields `to` again. Both paths are normalized first, a trailing
+/// `/` on `to` is kept, and `./` is returned for the directory itself.
+///
+/// # Examples
+///
+/// ```
+/// use yew_nav_link::relative_path;
+///
+/// assert_eq!(
+///     relative_path("/users/42/edit", "/settings"),
+///     "../../settings"
+/// );
+/// assert_eq!(relative_path("/users/42/edit", "/users/42/view"), "view");
+/// assert_eq!(relative_path("/users/42/edit", "/users/"), "../");
+/// assert_eq!(relative_path("/docs/", "/docs/api"), "api");
+/// ```
+#[must_use]
+pub fn relative_path(from: &str, to: &str) -> String {
+    let from = normalize_path(from);
+    let to = normalize_path(to);
+
+    let mut base: Vec<&str> = path_segments(&from).collect();
+    if !from.ends_with('/') {
+        base.pop();
+    }
+    let target: Vec<&str> = path_segments(&to).collect();
+    let keeps_slash = to.ends_with('/') || target.is_empty();
+
+    let mut shared = base
+        .iter()
+        .zip(&target)
+        .take_while(|(x, y)| segments_eq(x, y))
+        .count();
+    // Reaching `/a/b` from inside `/a/b/` needs `../b`: a bare `./` would
+    // resolve to `/a/b/`, a different path.
+    if shared == target.len() && !keeps_slash {
+        shared -= 1;
+    }
+
+    let mut reference = "../".repeat(base.len() - shared);
+    let rest = target[shared..].join("/");
+    if reference.is_empty() && rest.split('/').next().is_some_and(|s| s.contains(':')) {
+        // A leading `a:b` segment would otherwise read as a scheme.
+        reference.push_str("./");
+    }
+    reference.push_str(&rest);
+    if keeps_slash && !rest.is_empty() {
+        reference.push('/');
+    }
+    if reference.is_empty() {
+        reference.push_str("./");
+    }
+    reference
+}
+
+/// Parameters captured by [`PathPattern::matches`] or passed to
+/// [`PathPattern::build`], keyed by parameter name in insertion order.
+///
+/// Values are always decoded; [`PathPattern::build`] encodes them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PathParams {
+    params: Vec<(String, String)>
+}
+
+impl PathParams {
+    /// Creates an empty [`PathParams`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value of the named parameter.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find_map(|(key, value)| (key == name).then_some(value.as_str()))
+    }
+
+    /// Sets the named parameter, replacing any previous value.
+    pub fn insert(&mut self, name: &str, value: &str) {
+        match self.params.iter_mut().find(|(key, _)| key == name) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => self.params.push((name.to_string(), value.to_string()))
+        }
+    }
+
+    /// Returns an iterator over `(name, value)` pairs in insertion order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Returns the number of parameters.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Returns `true` if there are no parameters.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+}
+
+impl<K: AsRef<str>, V: AsRef<str>> FromIterator<(K, V)> for PathParams {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut params = Self::new();
+        for (name, value) in iter {
+            params.insert(name.as_ref(), value.as_ref());
+        }
+        params
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum PatternSegment {
+    Literal(String),
+    Param(String),
+    Optional(String),
+    Wildcard(String)
+}
+
+/// A compiled path template such as `/users/:id/posts/:slug?` or
+/// `/files/*path`.
+///
+/// | Segment | Matches |
+/// |---------|---------|
+/// | `users` | exactly that segment |
+/// | `:id` | any one segment, captured as `id` |
+/// | `:slug?` | one segment if present, captured as `slug` |
+/// | `*path` | the remaining zero or more segments, captured as `path` joined by `/`; must come last |
+///
+/// Matching ignores empty segments, so a trailing `/` or a doubled `//`
+/// makes no difference. Path segments are compared and captured
+/// percent-decoded with [`EncodeSet::PathSegment`] rules; literal segments
+/// of the template are decoded the same way when it is parsed, so
+/// `/tags/c%2B%2B` and `/tags/c++` describe the same pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::utils::{PathParams, PathPattern};
+///
+/// let pattern = PathPattern::parse("/users/:id/posts/:slug?")?;
+///
+/// let params = pattern.matches("/users/j%C3%BCrgen/posts").unwrap();
+/// assert_eq!(params.get("id"), Some("jürgen"));
+/// assert_eq!(params.get("slug"), None);
+///
+/// let params: PathParams = [("id", "a b"), ("slug", "hello")].into_iter().collect();
+/// assert_eq!(pattern.build(&params)?, "/users/a%20b/posts/hello");
+/// # Ok::<(), yew_nav_link::NavError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PathPattern {
+    template: String,
+    segments: Vec<PatternSegment>
+}
+
+impl PathPattern {
+    /// Compiles a template.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NavError::InvalidRoute`] when a parameter has an empty name,
+    /// a name is used twice, a `*wildcard` is not the last segment, or a
+    /// literal segment does not percent-decode to valid UTF-8.
+    pub fn parse(template: &str) -> NavResult<Self> {
+        let mut segments = Vec::new();
+        let mut raw = path_segments(template).peekable();
+        while let Some(segment) = raw.next() {
+            let parsed = if let Some(name) = segment.strip_prefix('*') {
+                if raw.peek().is_some() {
+                    return Err(NavError::invalid_route(format!(
+                        "pattern: wildcard `{segment}` must be the last segment"
+                    )));
+                }
+                PatternSegment::Wildcard(name.to_string())
+            } else if let Some(name) = segment.strip_prefix(':') {
+                name.strip_suffix('?').map_or_else(
+                    || PatternSegment::Param(name.to_string()),
+                    |name| PatternSegment::Optional(name.to_string())
+                )
+            } else {
+                let literal = decode_with(segment, EncodeSet::PathSegment).ok_or_else(|| {
+                    NavError::invalid_route(format!(
+                        "pattern: segment `{segment}` does not decode to UTF-8"
+                    ))
+                })?;
+                PatternSegment::Literal(literal)
+            };
+            if let Some(name) = parsed.name() {
+                if name.is_empty() {
+                    return Err(NavError::invalid_route(format!(
+                        "pattern: empty parameter name in `{template}`"
+                    )));
+                }
+                if segments
+                    .iter()
+                    .any(|s: &PatternSegment| s.name() == Some(name))
+                {
+                    return Err(NavError::invalid_route(format!(
+                        "pattern: duplicate parameter `{name}`"
+                    )));
+                }
+            }
+            segments.push(parsed);
+        }
+        Ok(Self {
+            template: template.to_string(),
+            segments
+        })
+    }
+
+    /// Matches a path (without query or fragment) against the pattern,
+    /// returning the captured parameters.
+    ///
+    /// Returns `None` when the path does not match or a segment does not
+    /// decode to valid UTF-8. An absent `:optional?` parameter is left out
+    /// of the result; an empty `*wildcard` is captured as `""`.
+    #[must_use]
+    pub fn matches(&self, path: &str) -> Option<PathParams> {
+        let decoded = path_segments(path)
+            .map(|s| decode_with(s, EncodeSet::PathSegment))
+            .collect::<Option<Vec<_>>>()?;
+        let mut captured = Vec::new();
+        self.match_from(0, &decoded, &mut captured)
+            .then(|| captured.into_iter().collect())
+    }
+
+    fn match_from<'a>(
+        &'a self,
+        index: usize,
+        path: &[String],
+        captured: &mut Vec<(&'a str, String)>
+    ) -> bool {
+        let Some(segment) = self.segments.get(index) else {
+            return path.is_empty();
+        };
+        match segment {
+            PatternSegment::Literal(literal) => {
+                path.first() == Some(literal) && self.match_from(index + 1, &path[1..], captured)
+            }
+            PatternSegment::Param(name) => {
+                let Some(value) = path.first() else {
+                    return false;
+                };
+                captured.push((name, value.clone()));
+                self.match_from(index + 1, &path[1..], captured) || {
+                    captured.pop();
+                    false
+                }
+            }
+            PatternSegment::Optional(name) => {
+                if let Some(value) = path.first() {
+                    captured.push((name, value.clone()));
+                    if self.match_from(index + 1, &path[1..], captured) {
+                        return true;
+                    }
+                    captured.pop();
+                }
+                self.match_from(index + 1, path, captured)
+            }
+            PatternSegment::Wildcard(name) => {
+                captured.push((name, path.join("/")));
+                true
+            }
+        }
+    }
+
+    /// Fills the template with `params`, percent-encoding every segment with
+    /// [`EncodeSet::PathSegment`]. A `*wildcard` value is split on `/` and
+    /// each piece encoded separately.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NavError::InvalidRoute`] when a required `:param` is
+    /// missing from `params` or empty: the built path would drop the segment
+    /// and no longer match the pattern. Empty `:param?` and `*wildcard`
+    /// values are skipped.
+    pub fn build(&self, params: &PathParams) -> NavResult<String> {
+        let mut path = String::new();
+        for segment in &self.segments {
+            let value = match segment {
+                PatternSegment::Literal(literal) => {
+                    Some(encode_with(literal, EncodeSet::PathSegment))
+                }
+                PatternSegment::Param(name) => {
+                    let value = params.get(name).ok_or_else(|| {
+                        NavError::invalid_route(format!("missing path parameter `{name}`"))
+                    })?;
+                    if value.is_empty() {
+                        return Err(NavError::invalid_route(format!(
+                            "empty path parameter `{name}`"
+                        )));
+                    }
+                    Some(encode_with(value, EncodeSet::PathSegment))
+                }
+                PatternSegment::Optional(name) => params
+                    .get(name)
+                    .filter(|value| !value.is_empty())
+                    .map(|value| encode_with(value, EncodeSet::PathSegment)),
+                PatternSegment::Wildcard(name) => params.get(name).map(|value| {
+                    value
+                        .split('/')
+                        .filter(|s| !s.is_empty())
+                        .map(|s| encode_with(s, EncodeSet::PathSegment))
+                        .collect::<Vec<_>>()
+                        .join("/")
+                })
+            };
+            if let Some(value) = value.filter(|v| !v.is_empty()) {
+                path.push('/');
+                path.push_str(&value);
+            }
+        }
+        if path.is_empty() {
+            path.push('/');
+        }
+        Ok(path)
+    }
+}
+
+impl PatternSegment {
+    fn name(&self) -> Option<&str> {
+        match self {
+            Self::Literal(_) => None,
+            Self::Param(name) | Self::Optional(name) | Self::Wildcard(name) => Some(name)
+        }
+    }
+}
+
+impl fmt::Display for PathPattern {
+    /// Writes the template the pattern was compiled from.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.template)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,4 +736,282 @@ mod tests {
         assert_eq!(join_paths("/base", "docs"), "/base/docs");
         assert_eq!(join_paths("/base/", "docs"), "/base/docs");
     }
+
+    #[test]
+    fn pattern_matches_literals_and_params() {
+        let pattern = PathPattern::parse("/users/:id/posts").unwrap();
+        let params = pattern.matches("/users/42/posts").unwrap();
+        assert_eq!(params.get("id"), Some("42"));
+        assert_eq!(params.len(), 1);
+        assert!(pattern.matches("/users/42").is_none());
+        assert!(pattern.matches("/users/42/posts/7").is_none());
+        assert!(pattern.matches("/teams/42/posts").is_none());
+    }
+
+    #[test]
+    fn pattern_ignores_empty_segments() {
+        let pattern = PathPattern::parse("/docs/:page").unwrap();
+        assert_eq!(
+            pattern.matches("//docs/intro/").unwrap().get("page"),
+            Some("intro")
+        );
+    }
+
+    #[test]
+    fn pattern_decodes_segments() {
+        let pattern = PathPattern::parse("/tags/c++/:name").unwrap();
+        let params = pattern.matches("/tags/c%2B%2B/a%2Fb").unwrap();
+        assert_eq!(params.get("name"), Some("a/b"));
+        assert!(pattern.matches("/tags/c++/%FF").is_none());
+    }
+
+    #[test]
+    fn pattern_encoded_literals_round_trip() {
+        let pattern = PathPattern::parse("/caf%C3%A9/a%20b/:id").unwrap();
+        let mut params = PathParams::new();
+        params.insert("id", "1");
+        let path = pattern.build(&params).unwrap();
+        assert_eq!(path, "/caf%C3%A9/a%20b/1");
+        assert_eq!(pattern.matches(&path).unwrap().get("id"), Some("1"));
+        assert!(pattern.matches("/café/a b/1").is_some());
+        assert_eq!(
+            PathPattern::parse("/bad/%FF").unwrap_err(),
+            NavError::invalid_route("pattern: segment `%FF` does not decode to UTF-8")
+        );
+    }
+
+    #[test]
+    fn pattern_optional_param_may_be_absent() {
+        let pattern = PathPattern::parse("/:lang?/docs/:page?").unwrap();
+        let full = pattern.matches("/de/docs/intro").unwrap();
+        assert_eq!(full.get("lang"), Some("de"));
+        assert_eq!(full.get("page"), Some("intro"));
+
+        let bare = pattern.matches("/docs").unwrap();
+        assert!(bare.is_empty());
+
+        let page_only = pattern.matches("/docs/intro").unwrap();
+        assert_eq!(page_only.get("lang"), None);
+        assert_eq!(page_only.get("page"), Some("intro"));
+    }
+
+    #[test]
+    fn pattern_wildcard_captures_the_rest() {
+        let pattern = PathPattern::parse("/files/*path").unwrap();
+        assert_eq!(
+            pattern.matches("/files/a/b%20c/d.txt").unwrap().get("path"),
+            Some("a/b c/d.txt")
+        );
+        assert_eq!(pattern.matches("/files").unwrap().get("path"), Some(""));
+        assert!(pattern.matches("/other/a").is_none());
+    }
+
+    #[test]
+    fn pattern_root_matches_only_root() {
+        let pattern = PathPattern::parse("/").unwrap();
+        assert!(pattern.matches("/").is_some());
+        assert!(pattern.matches("").is_some());
+        assert!(pattern.matches("/docs").is_none());
+    }
+
+    #[test]
+    fn pattern_parse_rejects_invalid_templates() {
+        assert_eq!(
+            PathPattern::parse("/files/*rest/more").unwrap_err(),
+            NavError::invalid_route("pattern: wildcard `*rest` must be the last segment")
+        );
+        assert_eq!(
+            PathPattern::parse("/users/:").unwrap_err(),
+            NavError::invalid_route("pattern: empty parameter name in `/users/:`")
+        );
+        assert_eq!(
+            PathPattern::parse("/:id/x/:id?").unwrap_err(),
+            NavError::invalid_route("pattern: duplicate parameter `id`")
+        );
+    }
+
+    #[test]
+    fn pattern_build_encodes_values() {
+        let pattern = PathPattern::parse("/users/:id/files/*path").unwrap();
+        let params: PathParams = [("id", "jürgen"), ("path", "a b/c?d")]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            pattern.build(&params).unwrap(),
+            "/users/j%C3%BCrgen/files/a%20b/c%3Fd"
+        );
+    }
+
+    #[test]
+    fn pattern_build_skips_absent_optionals_and_reports_missing_params() {
+        let pattern = PathPattern::parse("/:lang?/docs/:page").unwrap();
+        let mut params = PathParams::new();
+        assert_eq!(
+            pattern.build(&params).unwrap_err(),
+            NavError::invalid_route("missing path parameter `page`")
+        );
+        params.insert("page", "intro");
+        assert_eq!(pattern.build(&params).unwrap(), "/docs/intro");
+        params.insert("lang", "de");
+        assert_eq!(pattern.build(&params).unwrap(), "/de/docs/intro");
+        assert_eq!(
+            PathPattern::parse("/").unwrap().build(&params).unwrap(),
+            "/"
+        );
+    }
+
+    #[test]
+    fn pattern_build_rejects_empty_required_params() {
+        let pattern = PathPattern::parse("/users/:id/:tab?").unwrap();
+        let mut params = PathParams::new();
+        params.insert("id", "");
+        assert_eq!(
+            pattern.build(&params).unwrap_err(),
+            NavError::invalid_route("empty path parameter `id`")
+        );
+        params.insert("id", "7");
+        params.insert("tab", "");
+        let built = pattern.build(&params).unwrap();
+        assert_eq!(built, "/users/7");
+        assert_eq!(pattern.matches(&built).unwrap().get("id"), Some("7"));
+    }
+
+    #[test]
+    fn pattern_build_then_match_round_trips() {
+        let pattern = PathPattern::parse("/a/:x/*rest").unwrap();
+        let params: PathParams = [("x", "50%"), ("rest", "y/z")].into_iter().collect();
+        let built = pattern.build(&params).unwrap();
+        assert_eq!(pattern.matches(&built), Some(params));
+    }
+
+    #[test]
+    fn pattern_display_is_the_template() {
+        let pattern = PathPattern::parse("/users/:id").unwrap();
+        assert_eq!(pattern.to_string(), "/users/:id");
+    }
+
+    #[test]
+    fn path_params_insert_replaces() {
+        let mut params = PathParams::new();
+        params.insert("id", "1");
+        params.insert("id", "2");
+        assert_eq!(params.len(), 1);
+        assert_eq!(params.iter().collect::<Vec<_>>(), vec![("id", "2")]);
+    }
+
+    #[test]
+    fn path_segments_skip_empty_and_keep_escapes() {
+        assert_eq!(
+            path_segments("/a//b%2Fc/").collect::<Vec<_>>(),
+            ["a", "b%2Fc"]
+        );
+        assert_eq!(path_segments("/").count(), 0);
+        assert_eq!(path_segments("").count(), 0);
+        assert_eq!(path_segments("/a/b").next_back(), Some("b"));
+    }
+
+    #[test]
+    fn segments_eq_normalizes_escapes() {
+        assert!(segments_eq("caf%C3%A9", "café"));
+        assert!(segments_eq("%7e", "~"));
+        assert!(!segments_eq("a%2Fb", "a"));
+        assert!(!segments_eq("%FF", "%FE"));
+    }
+
+    #[test]
+    fn parent_path_drops_last_segment() {
+        assert_eq!(parent_path("/a/b/c").as_deref(), Some("/a/b"));
+        assert_eq!(parent_path("/a/b/c/").as_deref(), Some("/a/b"));
+        assert_eq!(parent_path("/a").as_deref(), Some("/"));
+        assert_eq!(parent_path("a/b").as_deref(), Some("a"));
+        assert_eq!(parent_path("a").as_deref(), Some(""));
+        assert_eq!(parent_path("/"), None);
+        assert_eq!(parent_path(""), None);
+    }
+
+    #[test]
+    fn common_prefix_is_segment_wise() {
+        assert_eq!(common_prefix("/docs/api", "/docs/api"), "/docs/api");
+        assert_eq!(common_prefix("/docs/api", "/docs/apis"), "/docs");
+        assert_eq!(common_prefix("/caf%C3%A9/x", "/café/y"), "/caf%C3%A9");
+        assert_eq!(common_prefix("/a", "/b"), "/");
+        assert_eq!(common_prefix("a/b", "a/c"), "a");
+        assert_eq!(common_prefix("a", "b"), "");
+    }
+
+    #[test]
+    fn strip_base_removes_segment_prefix() {
+        assert_eq!(strip_base("/app/docs", "/app"), Some("/docs"));
+        assert_eq!(strip_base("/app/docs/", "app"), Some("/docs/"));
+        assert_eq!(strip_base("/app", "/app"), Some("/"));
+        assert_eq!(strip_base("/app/", "/app"), Some("/"));
+        assert_eq!(strip_base("//app//docs", "/app"), Some("//docs"));
+        assert_eq!(strip_base("/%61pp/docs", "/app"), Some("/docs"));
+        assert_eq!(strip_base("/docs", "/"), Some("/docs"));
+        assert_eq!(strip_base("", ""), Some("/"));
+        assert_eq!(strip_base("/apps", "/app"), None);
+        assert_eq!(strip_base("/app", "/app/docs"), None);
+    }
+
+    #[test]
+    fn with_basename_prefixes_like_the_router() {
+        assert_eq!(with_basename("/docs", "/app/v2"), "/app/v2/docs");
+        assert_eq!(with_basename("/docs", "/app/v2/"), "/app/v2/docs");
+        assert_eq!(with_basename("/", "/app"), "/app/");
+        assert_eq!(with_basename("docs?q=1", "app"), "/app/docs?q=1");
+        assert_eq!(with_basename("/docs", "/"), "/docs");
+        assert_eq!(with_basename("", ""), "/");
+    }
+
+    #[test]
+    fn strip_basename_inverts_with_basename() {
+        for path in ["/", "/docs", "/docs/api/", "/users/hello%20world"] {
+            let prefixed = with_basename(path, "/app/v2");
+            assert_eq!(strip_basename(&prefixed, "/app/v2"), path, "path {path:?}");
+        }
+        assert_eq!(strip_basename("/app/v2", "/app/v2"), "/");
+        assert_eq!(strip_basename("/app/v20/docs", "/app/v2"), "/app/v20/docs");
+        assert_eq!(strip_basename("/docs", ""), "/docs");
+    }
+
+    #[test]
+    fn relative_path_between_pages() {
+        for (from, to, expected) in [
+            ("/users/42/edit", "/settings", "../../settings"),
+            ("/users/42/edit", "/users/42/edit", "edit"),
+            ("/users/42/edit", "/users/42/", "./"),
+            ("/users/42/edit", "/users/42", "../42"),
+            ("/users/42/edit", "/", "../../"),
+            ("/users/", "/users/42", "42"),
+            ("/users", "/users/42", "users/42"),
+            ("/a", "/", "./"),
+            ("/a/b/", "/a/c/d/", "../c/d/"),
+            ("/a/b", "/a/x:y", "./x:y"),
+            ("/a/./b/../c", "/a/d", "d")
+        ] {
+            assert_eq!(relative_path(from, to), expected, "from {from:?} to {to:?}");
+        }
+    }
+
+    #[test]
+    fn relative_path_resolves_back_to_target() {
+        use crate::utils::UrlParts;
+
+        for (from, to) in [
+            ("/users/42/edit", "/settings"),
+            ("/users/42/edit", "/users/42"),
+            ("/docs/", "/docs/api/hooks/"),
+            ("/a/b/c", "/"),
+            ("/", "/x/y"),
+            ("/a/b", "/a/x:y")
+        ] {
+            let base = UrlParts::parse(from);
+            let reference = relative_path(from, to);
+            assert_eq!(
+                UrlParts::resolve(&base, &reference).path,
+                to,
+                "from {from:?} via {reference:?}"
+            );
+        }
+    }
 }
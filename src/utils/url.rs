@@ -3,12 +3,24 @@
 
 //! URL parsing utilities.
 //!
-//! Provides helpers for parsing URLs and query parameters.
+//! Provides helpers for parsing, building, and serializing URLs and query
+//! parameters.
 
+mod builder;
 mod codec;
+mod host;
+mod origin;
 mod parts;
 mod query;
+mod resolve;
 
-pub use codec::{percent_decode, urlencoding_decode, urlencoding_encode};
+pub use builder::UrlBuilder;
+pub use codec::{
+    DecodeError, DecodeErrorKind, EncodeSet, Encoded, decode_cow_with, decode_with, encode_with,
+    percent_decode, percent_decode_cow, try_decode_with, try_percent_decode, urlencoding_decode,
+    urlencoding_decode_cow, urlencoding_encode
+};
+pub use host::Host;
+pub use origin::{Origin, default_port};
 pub use parts::UrlParts;
-pub use query::QueryParams;
+pub use query::{QueryEncoding, QueryParams};
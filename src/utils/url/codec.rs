@@ -2,21 +2,246 @@
 // SPDX-License-Identifier: MIT
 
 //! URL encoding/decoding utilities.
+//!
+//! Every URL component has its own set of characters that may appear
+//! unescaped. [`EncodeSet`] names those sets, and [`encode_with`] /
+//! [`decode_with`] apply them; [`urlencoding_encode`],
+//! [`urlencoding_decode`], and [`percent_decode`] are the
+//! [`EncodeSet::Form`] and [`EncodeSet::PathSegment`] shorthands.
+//!
+//! The `*_cow` decoders borrow the input when it contains nothing to decode,
+//! and [`Encoded`] streams an encoding into any formatter, so hot paths that
+//! run on every render skip the decode and encode buffers. Results stored in
+//! owned fields, such as query values and breadcrumb paths, are still copied
+//! once.
+
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult, Write}
+};
+
+/// Uppercase hex digits used for `%XX` escapes, as RFC 3986 §2.1 recommends.
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// The escaping profile applied by [`encode_with`] and [`decode_with`].
+///
+/// Unreserved characters (`a-z`, `A-Z`, `0-9`, `-`, `_`, `.`, `~`) pass
+/// through every set unchanged. The sets differ in which RFC 3986 delimiters
+/// stay literal and in how a space is written:
+///
+/// | Set | Also kept literal | Space |
+/// |-----|-------------------|-------|
+/// | `Form` | — | `+` |
+/// | `PathSegment` | `! $ & ' ( ) * + , ; = : @` | `%20` |
+/// | `QueryComponent` | `! $ ' ( ) * , ; : @ / ?` | `%20` |
+/// | `Fragment` | `! $ & ' ( ) * + , ; = : @ / ?` | `%20` |
+/// | `Userinfo` | `! $ & ' ( ) * + , ; =` | `%20` |
+///
+/// Only [`EncodeSet::Form`] decodes `+` as a space; in every other component
+/// `+` is a literal character.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::utils::{EncodeSet, encode_with};
+///
+/// assert_eq!(encode_with("a b/c", EncodeSet::Form), "a+b%2Fc");
+/// assert_eq!(encode_with("a b/c", EncodeSet::PathSegment), "a%20b%2Fc");
+/// assert_eq!(encode_with("a b/c", EncodeSet::Fragment), "a%20b/c");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[must_use]
+pub enum EncodeSet {
+    /// `application/x-www-form-urlencoded`: only unreserved characters stay
+    /// literal and a space becomes `+`.
+    Form,
+    /// A single RFC 3986 path segment (`pchar`); `/` is escaped so the value
+    /// cannot introduce a new segment.
+    PathSegment,
+    /// A key or value inside a query string. `&`, `=`, `+`, and `#` are
+    /// escaped so the value cannot split or terminate the pair.
+    QueryComponent,
+    /// An RFC 3986 fragment (the part after `#`).
+    Fragment,
+    /// A username or password inside an authority. `:` and `@` are escaped
+    /// so the value cannot split the userinfo or end the authority.
+    Userinfo
+}
 
-use std::fmt::Write;
+impl EncodeSet {
+    /// Returns `true` when `c` is written as-is by this set.
+    const fn keeps(self, c: char) -> bool {
+        if matches!(c, 'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' | '~') {
+            return true;
+        }
+        match self {
+            Self::Form => false,
+            Self::PathSegment => matches!(
+                c,
+                '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '=' | ':' | '@'
+            ),
+            Self::QueryComponent => matches!(
+                c,
+                '!' | '$' | '\'' | '(' | ')' | '*' | ',' | ';' | ':' | '@' | '/' | '?'
+            ),
+            Self::Fragment => matches!(
+                c,
+                '!' | '$'
+                    | '&'
+                    | '\''
+                    | '('
+                    | ')'
+                    | '*'
+                    | '+'
+                    | ','
+                    | ';'
+                    | '='
+                    | ':'
+                    | '@'
+                    | '/'
+                    | '?'
+            ),
+            Self::Userinfo => matches!(
+                c,
+                '!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' | '='
+            )
+        }
+    }
 
-/// Decodes a percent-encoded string. Also converts `+` to a space (the
-/// `application/x-www-form-urlencoded` convention).
+    /// Returns `true` for the form-urlencoded convention of `+` meaning a
+    /// space, in both directions.
+    const fn plus_is_space(self) -> bool {
+        matches!(self, Self::Form)
+    }
+}
+
+/// Percent-encodes `input` for the URL component described by `set`.
 ///
-/// `%XX` sequences accumulate into a byte buffer and the buffer is then
-/// decoded as UTF-8 — so a sequence like `"%E2%9C%93"` resolves to `Some("✓")`,
-/// not to three separate Latin-1 chars. Malformed `%XX` triplets are left in
-/// the output verbatim. Returns `None` if the resulting bytes are not valid
-/// UTF-8.
+/// Characters outside the set are written as the `%XX` escapes of their
+/// UTF-8 bytes. See [`EncodeSet`] for what each set keeps literal.
 #[must_use]
-pub fn urlencoding_decode(input: &str) -> Option<String> {
+pub fn encode_with(input: &str, set: EncodeSet) -> String {
+    let mut result = String::with_capacity(input.len() * 3);
+    let _ = write!(result, "{}", Encoded::new(input, set));
+    result
+}
+
+/// A [`Display`] adapter that percent-encodes its input while formatting.
+///
+/// Writing an `Encoded` produces exactly what [`encode_with`] returns, but
+/// streams runs of literal characters straight into the formatter instead of
+/// building an intermediate `String`. Use it inside `format!`/`write!` when
+/// the encoded value is only an ingredient of a larger string.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::utils::{EncodeSet, Encoded};
+///
+/// let href = format!("/search?q={}", Encoded::form("rust & wasm"));
+/// assert_eq!(href, "/search?q=rust+%26+wasm");
+///
+/// let segment = Encoded::new("a/b", EncodeSet::PathSegment);
+/// assert_eq!(segment.to_string(), "a%2Fb");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Encoded<'a> {
+    input: &'a str,
+    set:   EncodeSet
+}
+
+impl<'a> Encoded<'a> {
+    /// Wraps `input` for encoding with `set`.
+    #[must_use]
+    pub const fn new(input: &'a str, set: EncodeSet) -> Self {
+        Self {
+            input,
+            set
+        }
+    }
+
+    /// Wraps `input` for encoding with [`EncodeSet::Form`], the streaming
+    /// counterpart of [`urlencoding_encode`].
+    #[must_use]
+    pub const fn form(input: &'a str) -> Self {
+        Self::new(input, EncodeSet::Form)
+    }
+}
+
+impl Display for Encoded<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut literal_start = 0;
+        for (index, c) in self.input.char_indices() {
+            if self.set.keeps(c) {
+                continue;
+            }
+            f.write_str(&self.input[literal_start..index])?;
+            literal_start = index + c.len_utf8();
+            if c == ' ' && self.set.plus_is_space() {
+                f.write_char('+')?;
+            } else {
+                let mut buf = [0u8; 4];
+                for &byte in c.encode_utf8(&mut buf).as_bytes() {
+                    f.write_char('%')?;
+                    f.write_char(char::from(HEX_DIGITS[usize::from(byte >> 4)]))?;
+                    f.write_char(char::from(HEX_DIGITS[usize::from(byte & 0x0F)]))?;
+                }
+            }
+        }
+        f.write_str(&self.input[literal_start..])
+    }
+}
+
+/// Decodes the `%XX` escapes of a URL component encoded with `set`.
+///
+/// [`EncodeSet::Form`] additionally turns `+` into a space; every other set
+/// keeps `+` literal. `%XX` sequences accumulate into a byte buffer and the
+/// buffer is then decoded as UTF-8 — so a sequence like `"%E2%9C%93"`
+/// resolves to `Some("✓")`, not to three separate Latin-1 chars. Malformed
+/// `%XX` triplets are left in the output verbatim. Returns `None` if the
+/// resulting bytes are not valid UTF-8.
+#[must_use]
+pub fn decode_with(input: &str, set: EncodeSet) -> Option<String> {
+    decode_cow_with(input, set).map(Cow::into_owned)
+}
+
+/// Decodes like [`decode_with`], borrowing `input` when nothing in it needs
+/// decoding.
+///
+/// An input without `%` (and, for [`EncodeSet::Form`], without `+`) is
+/// returned as [`Cow::Borrowed`] with no allocation; only inputs that
+/// actually carry escapes pay for a new `String`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::borrow::Cow;
+///
+/// use yew_nav_link::utils::{EncodeSet, decode_cow_with};
+///
+/// assert!(matches!(
+///     decode_cow_with("plain", EncodeSet::Form),
+///     Some(Cow::Borrowed("plain"))
+/// ));
+/// assert_eq!(
+///     decode_cow_with("a%20b", EncodeSet::PathSegment).as_deref(),
+///     Some("a b")
+/// );
+/// ```
+#[must_use]
+pub fn decode_cow_with(input: &str, set: EncodeSet) -> Option<Cow<'_, str>> {
+    let Some(first_escape) = input
+        .bytes()
+        .position(|byte| byte == b'%' || (byte == b'+' && set.plus_is_space()))
+    else {
+        return Some(Cow::Borrowed(input));
+    };
+
+    let (literal, rest) = input.split_at(first_escape);
     let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
-    let mut chars = input.chars();
+    bytes.extend_from_slice(literal.as_bytes());
+    let mut chars = rest.chars();
 
     while let Some(c) = chars.next() {
         match c {
@@ -31,50 +256,177 @@ pub fn urlencoding_decode(input: &str) -> Option<String> {
                     push_utf8(&mut bytes, lo);
                 }
             }
-            '+' => bytes.push(b' '),
-            other => {
-                let mut buf = [0u8; 4];
-                bytes.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
-            }
+            '+' if set.plus_is_space() => bytes.push(b' '),
+            other => push_utf8(&mut bytes, Some(other))
         }
     }
 
-    String::from_utf8(bytes).ok()
+    String::from_utf8(bytes).ok().map(Cow::Owned)
+}
+
+/// [`urlencoding_decode`] that borrows `input` when it contains neither `%`
+/// nor `+`.
+#[must_use]
+pub fn urlencoding_decode_cow(input: &str) -> Option<Cow<'_, str>> {
+    decode_cow_with(input, EncodeSet::Form)
+}
+
+/// [`percent_decode`] that borrows `input` when it contains no `%`.
+#[must_use]
+pub fn percent_decode_cow(input: &str) -> Option<Cow<'_, str>> {
+    decode_cow_with(input, EncodeSet::PathSegment)
+}
+
+/// Decodes a percent-encoded string. Also converts `+` to a space (the
+/// `application/x-www-form-urlencoded` convention).
+///
+/// Shorthand for [`decode_with`] with [`EncodeSet::Form`]: `%XX` sequences
+/// decode as UTF-8 bytes, so `"%E2%9C%93"` resolves to `Some("✓")`.
+/// Malformed `%XX` triplets are left in the output verbatim. Returns `None`
+/// if the resulting bytes are not valid UTF-8.
+#[must_use]
+pub fn urlencoding_decode(input: &str) -> Option<String> {
+    decode_with(input, EncodeSet::Form)
 }
 
 /// Decodes `%XX` escape sequences without treating `+` as a space.
 ///
 /// Use this for path components, where `+` is a literal character; the
 /// `+`-to-space rule of [`urlencoding_decode`] applies only to
-/// `application/x-www-form-urlencoded` query strings. Malformed `%XX`
+/// `application/x-www-form-urlencoded` query strings. Shorthand for
+/// [`decode_with`] with [`EncodeSet::PathSegment`]. Malformed `%XX`
 /// triplets are left in the output verbatim. Returns `None` if the resulting
 /// bytes are not valid UTF-8.
 #[must_use]
 pub fn percent_decode(input: &str) -> Option<String> {
-    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
-    let mut chars = input.chars();
+    decode_with(input, EncodeSet::PathSegment)
+}
 
-    while let Some(c) = chars.next() {
-        match c {
-            '%' => {
-                let hi = chars.next();
-                let lo = chars.next();
-                if let Some(byte) = hex_pair_to_byte(hi, lo) {
-                    bytes.push(byte);
-                } else {
-                    bytes.push(b'%');
-                    push_utf8(&mut bytes, hi);
-                    push_utf8(&mut bytes, lo);
-                }
+/// What went wrong in a strict decode; see [`DecodeError`].
+///
+/// This enum is `#[non_exhaustive]`, like [`NavError`](crate::NavError):
+/// matches need a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DecodeErrorKind {
+    /// A `%` is followed by fewer than two characters.
+    TruncatedEscape,
+    /// A `%` is followed by characters that are not two hex digits.
+    InvalidHex,
+    /// The decoded bytes are not valid UTF-8.
+    InvalidUtf8
+}
+
+/// Error returned by the strict decoders ([`try_decode_with`],
+/// [`try_percent_decode`]).
+///
+/// `offset` is the byte offset into the decoder's input: the `%` that opens
+/// a bad escape, or the start of the escape (or literal byte) where the
+/// invalid UTF-8 sequence begins.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DecodeError {
+    /// Byte offset into the input at which decoding failed.
+    pub offset: usize,
+    /// The kind of failure.
+    pub kind:   DecodeErrorKind
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let what = match self.kind {
+            DecodeErrorKind::TruncatedEscape => "truncated percent-escape",
+            DecodeErrorKind::InvalidHex => "invalid hex digits in percent-escape",
+            DecodeErrorKind::InvalidUtf8 => "invalid UTF-8"
+        };
+        write!(f, "{what} at byte {}", self.offset)
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Strict counterpart of [`decode_with`]: rejects malformed input instead of
+/// passing it through.
+///
+/// Where the lenient decoders keep a malformed `%zz` verbatim and return
+/// `None` on invalid UTF-8, this reports the first problem as a
+/// [`DecodeError`] with its byte offset and [`DecodeErrorKind`].
+///
+/// # Errors
+///
+/// - [`DecodeErrorKind::TruncatedEscape`] when a `%` has fewer than two
+///   characters after it.
+/// - [`DecodeErrorKind::InvalidHex`] when the two characters after a `%` are
+///   not hex digits.
+/// - [`DecodeErrorKind::InvalidUtf8`] when the decoded bytes are not UTF-8.
+pub fn try_decode_with(input: &str, set: EncodeSet) -> Result<String, DecodeError> {
+    let raw = input.as_bytes();
+    let mut bytes: Vec<u8> = Vec::with_capacity(raw.len());
+    let mut origins: Vec<usize> = Vec::with_capacity(raw.len());
+    let mut index = 0;
+
+    while let Some(&byte) = raw.get(index) {
+        let decoded = match byte {
+            b'%' => {
+                let digit = |at: usize| {
+                    raw.get(at)
+                        .ok_or(DecodeErrorKind::TruncatedEscape)
+                        .and_then(|c| {
+                            char::from(*c)
+                                .to_digit(16)
+                                .ok_or(DecodeErrorKind::InvalidHex)
+                        })
+                };
+                let (high, low) = digit(index + 1)
+                    .and_then(|high| digit(index + 2).map(|low| (high, low)))
+                    .map_err(|kind| DecodeError {
+                        offset: index,
+                        kind
+                    })?;
+                origins.push(index);
+                index += 3;
+                u8::try_from(high * 16 + low).unwrap_or_default()
+            }
+            b'+' if set.plus_is_space() => {
+                origins.push(index);
+                index += 1;
+                b' '
             }
             other => {
-                let mut buf = [0u8; 4];
-                bytes.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
+                origins.push(index);
+                index += 1;
+                other
             }
-        }
+        };
+        bytes.push(decoded);
     }
 
-    String::from_utf8(bytes).ok()
+    String::from_utf8(bytes).map_err(|err| DecodeError {
+        offset: origins[err.utf8_error().valid_up_to()],
+        kind:   DecodeErrorKind::InvalidUtf8
+    })
+}
+
+/// Strict counterpart of [`percent_decode`]: [`try_decode_with`] with
+/// [`EncodeSet::PathSegment`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::utils::{DecodeErrorKind, try_percent_decode};
+///
+/// assert_eq!(try_percent_decode("c++%20x").as_deref(), Ok("c++ x"));
+///
+/// let err = try_percent_decode("100%zz").unwrap_err();
+/// assert_eq!(err.offset, 3);
+/// assert_eq!(err.kind, DecodeErrorKind::InvalidHex);
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`DecodeError`] for truncated or non-hex escapes and for
+/// decoded bytes that are not valid UTF-8.
+pub fn try_percent_decode(input: &str) -> Result<String, DecodeError> {
+    try_decode_with(input, EncodeSet::PathSegment)
 }
 
 /// Combines two hex-digit characters into the byte they encode.
@@ -98,27 +450,11 @@ fn push_utf8(bytes: &mut Vec<u8>, c: Option<char>) {
 /// Percent-encodes a string for safe use in URLs.
 ///
 /// Unreserved characters (`a-z`, `A-Z`, `0-9`, `-`, `_`, `.`, `~`) are
-/// passed through unchanged. Spaces are encoded as `+`.
+/// passed through unchanged. Spaces are encoded as `+`. Shorthand for
+/// [`encode_with`] with [`EncodeSet::Form`].
 #[must_use]
 pub fn urlencoding_encode(input: &str) -> String {
-    let mut result = String::with_capacity(input.len() * 3);
-
-    for c in input.chars() {
-        match c {
-            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' | '~' => {
-                result.push(c);
-            }
-            ' ' => result.push('+'),
-            _ => {
-                let mut buf = [0u8; 4];
-                for byte in c.encode_utf8(&mut buf).as_bytes() {
-                    let _ = write!(result, "%{byte:02X}");
-                }
-            }
-        }
-    }
-
-    result
+    encode_with(input, EncodeSet::Form)
 }
 
 #[cfg(test)]
@@ -260,4 +596,204 @@ mod tests {
         let result = urlencoding_encode("hello world!");
         assert!(result.contains("hello+world"));
     }
+
+    #[test]
+    fn encode_with_form_matches_urlencoding_encode() {
+        let input = "a b&c=d/e?f#g✓";
+        assert_eq!(
+            encode_with(input, EncodeSet::Form),
+            urlencoding_encode(input)
+        );
+    }
+
+    #[test]
+    fn encode_with_path_segment_escapes_slash_and_keeps_sub_delims() {
+        assert_eq!(
+            encode_with("a b/c+d:e@f", EncodeSet::PathSegment),
+            "a%20b%2Fc+d:e@f"
+        );
+        assert_eq!(encode_with("50%?#", EncodeSet::PathSegment), "50%25%3F%23");
+    }
+
+    #[test]
+    fn encode_with_query_component_escapes_pair_delimiters() {
+        assert_eq!(
+            encode_with("a=b&c+d #e", EncodeSet::QueryComponent),
+            "a%3Db%26c%2Bd%20%23e"
+        );
+        assert_eq!(encode_with("/path?x", EncodeSet::QueryComponent), "/path?x");
+    }
+
+    #[test]
+    fn encode_with_fragment_keeps_slash_and_question_mark() {
+        assert_eq!(
+            encode_with("sec/1?a=b&c #d", EncodeSet::Fragment),
+            "sec/1?a=b&c%20%23d"
+        );
+    }
+
+    #[test]
+    fn encode_with_userinfo_escapes_colon_and_at() {
+        assert_eq!(
+            encode_with("us:er@x/y", EncodeSet::Userinfo),
+            "us%3Aer%40x%2Fy"
+        );
+        assert_eq!(encode_with("p&ss+w=rd", EncodeSet::Userinfo), "p&ss+w=rd");
+    }
+
+    #[test]
+    fn decode_with_only_form_treats_plus_as_space() {
+        assert_eq!(decode_with("a+b", EncodeSet::Form), Some("a b".to_string()));
+        for set in [
+            EncodeSet::PathSegment,
+            EncodeSet::QueryComponent,
+            EncodeSet::Fragment,
+            EncodeSet::Userinfo
+        ] {
+            assert_eq!(decode_with("a+b", set), Some("a+b".to_string()));
+        }
+    }
+
+    #[test]
+    fn decode_with_round_trips_every_set() {
+        let input = "hé llo/wörld?a=b&c+d#e:f@g%h";
+        for set in [
+            EncodeSet::Form,
+            EncodeSet::PathSegment,
+            EncodeSet::QueryComponent,
+            EncodeSet::Fragment,
+            EncodeSet::Userinfo
+        ] {
+            let encoded = encode_with(input, set);
+            assert_eq!(
+                decode_with(&encoded, set),
+                Some(input.to_string()),
+                "{set:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn decode_cow_borrows_input_without_escapes() {
+        assert!(matches!(
+            urlencoding_decode_cow("hello-world"),
+            Some(Cow::Borrowed("hello-world"))
+        ));
+        assert!(matches!(
+            percent_decode_cow("/lang/c++"),
+            Some(Cow::Borrowed("/lang/c++"))
+        ));
+    }
+
+    #[test]
+    fn decode_cow_allocates_only_when_decoding() {
+        assert!(matches!(
+            urlencoding_decode_cow("a+b"),
+            Some(Cow::Owned(ref s)) if s == "a b"
+        ));
+        assert!(matches!(
+            percent_decode_cow("a%20b"),
+            Some(Cow::Owned(ref s)) if s == "a b"
+        ));
+        assert_eq!(percent_decode_cow("%FF"), None);
+        assert_eq!(urlencoding_decode_cow("100%zz").as_deref(), Some("100%zz"));
+    }
+
+    #[test]
+    fn encoded_display_matches_encode_with() {
+        let input = "héllo wörld/?a=b&c+d#e✓";
+        for set in [
+            EncodeSet::Form,
+            EncodeSet::PathSegment,
+            EncodeSet::QueryComponent,
+            EncodeSet::Fragment,
+            EncodeSet::Userinfo
+        ] {
+            assert_eq!(
+                Encoded::new(input, set).to_string(),
+                encode_with(input, set)
+            );
+        }
+    }
+
+    #[test]
+    fn encoded_form_streams_into_larger_strings() {
+        assert_eq!(
+            format!("?q={}&p=1", Encoded::form("a b&c")),
+            "?q=a+b%26c&p=1"
+        );
+        assert_eq!(Encoded::form("").to_string(), "");
+        assert_eq!(Encoded::form("plain").to_string(), "plain");
+    }
+
+    #[test]
+    fn try_percent_decode_accepts_well_formed_input() {
+        assert_eq!(try_percent_decode(""), Ok(String::new()));
+        assert_eq!(
+            try_percent_decode("/hello%20w%C3%B6rld/c++"),
+            Ok("/hello wörld/c++".to_string())
+        );
+    }
+
+    #[test]
+    fn try_percent_decode_reports_truncated_escape() {
+        for (input, offset) in [("%", 0), ("ab%2", 2), ("x%", 1)] {
+            assert_eq!(
+                try_percent_decode(input),
+                Err(DecodeError {
+                    offset,
+                    kind: DecodeErrorKind::TruncatedEscape
+                }),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn try_percent_decode_reports_invalid_hex() {
+        for (input, offset) in [("100%zz", 3), ("%2X", 0), ("a%z", 1), ("%é1", 0)] {
+            assert_eq!(
+                try_percent_decode(input),
+                Err(DecodeError {
+                    offset,
+                    kind: DecodeErrorKind::InvalidHex
+                }),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn try_percent_decode_reports_invalid_utf8_offset() {
+        assert_eq!(
+            try_percent_decode("ok/%FF"),
+            Err(DecodeError {
+                offset: 3,
+                kind:   DecodeErrorKind::InvalidUtf8
+            })
+        );
+        assert_eq!(
+            try_percent_decode("é%C3"),
+            Err(DecodeError {
+                offset: 2,
+                kind:   DecodeErrorKind::InvalidUtf8
+            })
+        );
+    }
+
+    #[test]
+    fn try_decode_with_form_maps_plus_to_space() {
+        assert_eq!(
+            try_decode_with("a+b%2B", EncodeSet::Form),
+            Ok("a b+".to_string())
+        );
+    }
+
+    #[test]
+    fn decode_error_display() {
+        let err = try_percent_decode("ab%2").unwrap_err();
+        assert_eq!(err.to_string(), "truncated percent-escape at byte 2");
+        let err = try_percent_decode("%FF").unwrap_err();
+        assert_eq!(err.to_string(), "invalid UTF-8 at byte 0");
+    }
 }
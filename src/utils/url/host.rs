@@ -0,0 +1,279 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! URL host classification and IDNA punycode.
+
+use std::{
+    fmt,
+    net::{Ipv4Addr, Ipv6Addr}
+};
+
+/// The host of a URL authority.
+///
+/// [`Host::parse`] never fails; text that is neither an IP address nor a
+/// domain name is kept as [`Host::Opaque`], so a parsed URL still
+/// serializes back to its input.
+///
+/// | Input | Variant |
+/// |-------|---------|
+/// | `example.com`, `bücher.de`, `xn--bcher-kva.de` | `Domain` |
+/// | `192.168.0.1` | `Ipv4` |
+/// | `[::1]` | `Ipv6` (brackets are not stored) |
+/// | `""`, `ex%41mple`, `[v1.future]`, `[::1` | `Opaque` |
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Host {
+    /// A registered domain name, as written. Labels may be internationalized
+    /// (`bücher.de`) or already punycode-encoded (`xn--bcher-kva.de`).
+    Domain(String),
+    /// A dotted-quad IPv4 address.
+    Ipv4(Ipv4Addr),
+    /// A bracketed IPv6 address. Serializes in its RFC 5952 canonical form.
+    Ipv6(Ipv6Addr),
+    /// Any other host text, kept verbatim.
+    Opaque(String)
+}
+
+impl Host {
+    /// Classifies host text taken from a URL authority.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::net::Ipv6Addr;
+    ///
+    /// use yew_nav_link::utils::Host;
+    ///
+    /// assert_eq!(
+    ///     Host::parse("example.com"),
+    ///     Host::Domain("example.com".into())
+    /// );
+    /// assert_eq!(Host::parse("[::1]"), Host::Ipv6(Ipv6Addr::LOCALHOST));
+    /// assert_eq!(Host::parse("ex%41mple"), Host::Opaque("ex%41mple".into()));
+    /// ```
+    #[must_use]
+    pub fn parse(host: &str) -> Self {
+        if let Some(inner) = host.strip_prefix('[').and_then(|h| h.strip_suffix(']'))
+            && let Ok(addr) = inner.parse()
+        {
+            return Self::Ipv6(addr);
+        }
+        if let Ok(addr) = host.parse() {
+            return Self::Ipv4(addr);
+        }
+        if is_domain(host) {
+            Self::Domain(host.to_string())
+        } else {
+            Self::Opaque(host.to_string())
+        }
+    }
+
+    /// Returns `true` for a domain with an internationalized label, either
+    /// in Unicode (`bücher.de`) or in punycode (`xn--bcher-kva.de`).
+    #[must_use]
+    pub fn is_idn(&self) -> bool {
+        match self {
+            Self::Domain(domain) => domain.split('.').any(|label| {
+                !label.is_ascii()
+                    || label
+                        .get(..4)
+                        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("xn--"))
+            }),
+            _ => false
+        }
+    }
+
+    /// Returns the host in the ASCII form used for comparisons: a domain is
+    /// lowercased and each non-ASCII label is punycode-encoded with an
+    /// `xn--` prefix. Other variants serialize as [`Display`](fmt::Display)
+    /// does.
+    ///
+    /// Only lowercasing is applied before encoding; the full UTS #46
+    /// mapping table is out of scope.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_nav_link::utils::Host;
+    ///
+    /// assert_eq!(Host::parse("Bücher.DE").to_ascii(), "xn--bcher-kva.de");
+    /// assert_eq!(Host::parse("[::1]").to_ascii(), "[::1]");
+    /// ```
+    #[must_use]
+    pub fn to_ascii(&self) -> String {
+        match self {
+            Self::Domain(domain) => domain
+                .split('.')
+                .map(|label| {
+                    let label = label.to_lowercase();
+                    if label.is_ascii() {
+                        label
+                    } else {
+                        punycode_encode(&label).map_or(label, |encoded| format!("xn--{encoded}"))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("."),
+            other => other.to_string()
+        }
+    }
+}
+
+impl fmt::Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Domain(host) | Self::Opaque(host) => f.write_str(host),
+            Self::Ipv4(addr) => write!(f, "{addr}"),
+            Self::Ipv6(addr) => write!(f, "[{addr}]")
+        }
+    }
+}
+
+/// Dot-separated labels of letters, digits, `-`, `_`, or non-ASCII
+/// characters, with an optional trailing dot.
+fn is_domain(host: &str) -> bool {
+    let host = host.strip_suffix('.').unwrap_or(host);
+    !host.is_empty()
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_') || !c.is_ascii())
+        })
+}
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+/// Bias adaptation, RFC 3492 §6.1.
+const fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+/// Punycode digit alphabet: values `0..26` are `a..z`, `26..36` are `0..9`.
+const DIGITS: &[u8; 36] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+fn encode_digit(digit: u32) -> char {
+    char::from(DIGITS[digit as usize])
+}
+
+/// Encodes one label with the RFC 3492 §6.3 punycode algorithm, without the
+/// `xn--` prefix. Returns `None` on arithmetic overflow.
+fn punycode_encode(label: &str) -> Option<String> {
+    let code_points: Vec<u32> = label.chars().map(u32::from).collect();
+    let mut output: String = label.chars().filter(char::is_ascii).collect();
+    let basic = u32::try_from(output.len()).ok()?;
+    let total = u32::try_from(code_points.len()).ok()?;
+    if basic > 0 {
+        output.push('-');
+    }
+
+    let (mut n, mut delta, mut bias, mut handled) = (INITIAL_N, 0u32, INITIAL_BIAS, basic);
+    while handled < total {
+        let next = code_points.iter().copied().filter(|&c| c >= n).min()?;
+        delta = delta.checked_add((next - n).checked_mul(handled + 1)?)?;
+        n = next;
+        for &c in &code_points {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = if k <= bias {
+                        T_MIN
+                    } else if k >= bias + T_MAX {
+                        T_MAX
+                    } else {
+                        k - bias
+                    };
+                    if q < t {
+                        break;
+                    }
+                    output.push(encode_digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(encode_digit(q));
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta = delta.checked_add(1)?;
+        n += 1;
+    }
+    Some(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_parse_classifies_variants() {
+        assert_eq!(
+            Host::parse("example.com"),
+            Host::Domain("example.com".into())
+        );
+        assert_eq!(Host::parse("localhost."), Host::Domain("localhost.".into()));
+        assert_eq!(Host::parse("127.0.0.1"), Host::Ipv4(Ipv4Addr::LOCALHOST));
+        assert_eq!(Host::parse("[::1]"), Host::Ipv6(Ipv6Addr::LOCALHOST));
+        assert_eq!(Host::parse(""), Host::Opaque(String::new()));
+        assert_eq!(Host::parse("[::1"), Host::Opaque("[::1".into()));
+        assert_eq!(Host::parse("[v1.x]"), Host::Opaque("[v1.x]".into()));
+        assert_eq!(Host::parse("a..b"), Host::Opaque("a..b".into()));
+    }
+
+    #[test]
+    fn host_parse_rejects_non_canonical_ipv4_as_domain() {
+        assert_eq!(Host::parse("01.2.3.4"), Host::Domain("01.2.3.4".into()));
+    }
+
+    #[test]
+    fn host_display_round_trips() {
+        for host in [
+            "example.com",
+            "bücher.de",
+            "10.0.0.1",
+            "[2001:db8::1]",
+            "ex%41mple"
+        ] {
+            assert_eq!(Host::parse(host).to_string(), host);
+        }
+    }
+
+    #[test]
+    fn host_is_idn() {
+        assert!(Host::parse("bücher.de").is_idn());
+        assert!(Host::parse("XN--bcher-kva.de").is_idn());
+        assert!(!Host::parse("example.com").is_idn());
+        assert!(!Host::parse("127.0.0.1").is_idn());
+    }
+
+    /// Well-known IDNA encodings; the last row only lowercases.
+    #[test]
+    fn host_to_ascii_punycode_vectors() {
+        for (host, ascii) in [
+            ("bücher.de", "xn--bcher-kva.de"),
+            ("münchen.de", "xn--mnchen-3ya.de"),
+            ("españa.com", "xn--espaa-rta.com"),
+            ("日本語.jp", "xn--wgv71a119e.jp"),
+            ("Example.COM", "example.com")
+        ] {
+            assert_eq!(Host::parse(host).to_ascii(), ascii, "host {host:?}");
+        }
+    }
+}
@@ -0,0 +1,207 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! URL origins and scheme default ports.
+
+use std::fmt;
+
+use super::{host::Host, parts::UrlParts};
+
+/// Returns the well-known default port of a scheme, compared
+/// case-insensitively.
+///
+/// | Scheme | Port |
+/// |--------|------|
+/// | `http`, `ws` | 80 |
+/// | `https`, `wss` | 443 |
+/// | `ftp` | 21 |
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_nav_link::utils::default_port;
+///
+/// assert_eq!(default_port("HTTPS"), Some(443));
+/// assert_eq!(default_port("mailto"), None);
+/// ```
+#[must_use]
+pub fn default_port(scheme: &str) -> Option<u16> {
+    match scheme.to_ascii_lowercase().as_str() {
+        "http" | "ws" => Some(80),
+        "https" | "wss" => Some(443),
+        "ftp" => Some(21),
+        _ => None
+    }
+}
+
+/// The `(scheme, host, port)` tuple origin of a URL.
+///
+/// Built by [`UrlParts::origin`] in normalized form: the scheme is
+/// lowercased, a domain host is in its [`Host::to_ascii`] form, and the port
+/// is the effective one, falling back to [`default_port`]. Two URLs are
+/// same-origin exactly when their origins are equal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Origin {
+    /// Lowercased scheme (e.g. `"https"`).
+    pub scheme: String,
+    /// Host in ASCII form.
+    pub host:   Host,
+    /// Explicit port, or the scheme's default port.
+    pub port:   Option<u16>
+}
+
+impl fmt::Display for Origin {
+    /// Writes `scheme://host[:port]`, omitting the scheme's default port,
+    /// like `window.location.origin`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://{}", self.scheme, self.host)?;
+        match self.port {
+            Some(port) if Some(port) != default_port(&self.scheme) => write!(f, ":{port}"),
+            _ => Ok(())
+        }
+    }
+}
+
+impl UrlParts {
+    /// Returns the explicit port, or the default port of the scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_nav_link::utils::UrlParts;
+    ///
+    /// assert_eq!(
+    ///     UrlParts::parse("https://example.com").port_or_default(),
+    ///     Some(443)
+    /// );
+    /// assert_eq!(
+    ///     UrlParts::parse("http://example.com:8080").port_or_default(),
+    ///     Some(8080)
+    /// );
+    /// assert_eq!(UrlParts::parse("/docs").port_or_default(), None);
+    /// ```
+    #[must_use]
+    pub fn port_or_default(&self) -> Option<u16> {
+        self.port
+            .or_else(|| self.scheme.as_deref().and_then(default_port))
+    }
+
+    /// Returns the URL's [`Origin`], or `None` for an opaque origin: a URL
+    /// without a scheme or host, or with a [`Host::Opaque`] host.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_nav_link::utils::UrlParts;
+    ///
+    /// let origin = UrlParts::parse("HTTPS://Bücher.de:443/katalog")
+    ///     .origin()
+    ///     .unwrap();
+    /// assert_eq!(origin.to_string(), "https://xn--bcher-kva.de");
+    /// assert_eq!(UrlParts::parse("/relative").origin(), None);
+    /// ```
+    #[must_use]
+    pub fn origin(&self) -> Option<Origin> {
+        let scheme = self.scheme.as_deref()?.to_ascii_lowercase();
+        let host = match self.host.as_ref()? {
+            Host::Opaque(_) => return None,
+            domain @ Host::Domain(_) => Host::Domain(domain.to_ascii()),
+            ip => ip.clone()
+        };
+        Some(Origin {
+            port: self.port.or_else(|| default_port(&scheme)),
+            scheme,
+            host
+        })
+    }
+
+    /// Returns `true` if both URLs have the same non-opaque [`Origin`].
+    ///
+    /// A link whose URL is not same-origin with the page leaves the app.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_nav_link::utils::UrlParts;
+    ///
+    /// let page = UrlParts::parse("https://example.com/docs");
+    /// assert!(page.is_same_origin(&UrlParts::parse("https://EXAMPLE.com:443/api")));
+    /// assert!(!page.is_same_origin(&UrlParts::parse("http://example.com/api")));
+    /// assert!(!page.is_same_origin(&UrlParts::parse("https://cdn.example.com/")));
+    /// ```
+    #[must_use]
+    pub fn is_same_origin(&self, other: &Self) -> bool {
+        self.origin()
+            .is_some_and(|origin| other.origin().as_ref() == Some(&origin))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    #[test]
+    fn default_port_known_schemes() {
+        assert_eq!(default_port("http"), Some(80));
+        assert_eq!(default_port("ws"), Some(80));
+        assert_eq!(default_port("https"), Some(443));
+        assert_eq!(default_port("WSS"), Some(443));
+        assert_eq!(default_port("ftp"), Some(21));
+        assert_eq!(default_port("file"), None);
+    }
+
+    #[test]
+    fn origin_normalizes_scheme_host_and_port() {
+        let origin = UrlParts::parse("HTTP://Example.COM/a").origin().unwrap();
+        assert_eq!(
+            origin,
+            Origin {
+                scheme: "http".to_string(),
+                host:   Host::Domain("example.com".to_string()),
+                port:   Some(80)
+            }
+        );
+        assert_eq!(origin.to_string(), "http://example.com");
+    }
+
+    #[test]
+    fn origin_keeps_ip_hosts_and_non_default_ports() {
+        let v4 = UrlParts::parse("http://127.0.0.1:8080/").origin().unwrap();
+        assert_eq!(v4.host, Host::Ipv4(Ipv4Addr::LOCALHOST));
+        assert_eq!(v4.to_string(), "http://127.0.0.1:8080");
+
+        let v6 = UrlParts::parse("https://[::1]/").origin().unwrap();
+        assert_eq!(v6.host, Host::Ipv6(Ipv6Addr::LOCALHOST));
+        assert_eq!(v6.to_string(), "https://[::1]");
+    }
+
+    #[test]
+    fn origin_is_none_when_opaque() {
+        assert_eq!(UrlParts::parse("/docs").origin(), None);
+        assert_eq!(UrlParts::parse("file:///etc/hosts").origin(), None);
+        assert_eq!(UrlParts::parse("https://ex%41mple/").origin(), None);
+    }
+
+    #[test]
+    fn origin_of_unknown_scheme_has_no_port() {
+        let origin = UrlParts::parse("gopher://example.com/").origin().unwrap();
+        assert_eq!(origin.port, None);
+        assert_eq!(origin.to_string(), "gopher://example.com");
+    }
+
+    #[test]
+    fn same_origin_compares_effective_port_and_idn() {
+        let page = UrlParts::parse("https://bücher.de/");
+        assert!(page.is_same_origin(&UrlParts::parse("https://xn--bcher-kva.de:443/x")));
+        assert!(!page.is_same_origin(&UrlParts::parse("https://bücher.de:8443/")));
+        assert!(!page.is_same_origin(&UrlParts::parse("wss://bücher.de/")));
+    }
+
+    #[test]
+    fn same_origin_is_false_for_opaque_origins() {
+        let relative = UrlParts::parse("/docs");
+        assert!(!relative.is_same_origin(&relative));
+    }
+}
@@ -12,9 +12,46 @@
 //! Keys map to **multiple values** to support query strings like
 //! `tag=rust&tag=web` properly.
 
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult, Write}
+};
+
+use super::codec::{EncodeSet, Encoded, decode_cow_with, try_decode_with};
+use crate::errors::{NavError, NavResult};
+
+/// Form-decodes `input`, borrowing it when there is nothing to decode and
+/// keeping it verbatim when the decoded bytes are not valid UTF-8.
+fn form_decode(input: &str) -> Cow<'_, str> {
+    decode_cow_with(input, EncodeSet::Form).unwrap_or(Cow::Borrowed(input))
+}
+
+/// How [`QueryParams::to_query_string_with`] escapes keys and values.
+///
+/// Both escape `&`, `=`, `+`, and `#`, so a key or value can never split a
+/// pair or end the query; the other [`EncodeSet`]s keep some of them
+/// literal and are not offered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[must_use]
+pub enum QueryEncoding {
+    /// `application/x-www-form-urlencoded`, as [`EncodeSet::Form`]: a space
+    /// becomes `+`.
+    #[default]
+    Form,
+    /// A query component, as [`EncodeSet::QueryComponent`]: a space becomes
+    /// `%20` and `/`, `?`, `:`, and `@` stay readable.
+    Component
+}
 
-use super::codec::{urlencoding_decode, urlencoding_encode};
+impl QueryEncoding {
+    /// Returns the [`EncodeSet`] applied to keys and values.
+    pub const fn encode_set(self) -> EncodeSet {
+        match self {
+            Self::Form => EncodeSet::Form,
+            Self::Component => EncodeSet::QueryComponent
+        }
+    }
+}
 
 /// A collection of URL query parameters with get, set, and serialization
 /// support.
@@ -67,6 +104,19 @@ impl QueryParams {
             .iter_mut()
             .find_map(|(k, values)| (k == key).then_some(values))
     }
+
+    /// Appends a decoded pair, skipping pairs whose key decodes to empty.
+    /// The key is only copied into an owned `String` when it is new.
+    fn push_decoded(&mut self, key: Cow<'_, str>, value: String) {
+        if key.is_empty() {
+            return;
+        }
+        match self.entry_mut(&key) {
+            Some(values) => values.push(value),
+            None => self.params.push((key.into_owned(), vec![value]))
+        }
+    }
+
     /// Creates an empty [`QueryParams`].
     #[must_use]
     pub fn new() -> Self {
@@ -78,6 +128,10 @@ impl QueryParams {
     /// Supports multiple values per key: `tag=rust&tag=web` stores both values
     /// under the key `"tag"`.
     ///
+    /// Keys and values without `%` or `+` are not decoded: a key borrows the
+    /// input until it is stored for the first time, and a value is copied
+    /// once into the `String` it is stored as.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -101,21 +155,65 @@ impl QueryParams {
             let (key, value) = pair
                 .split_once('=')
                 .map_or((pair, None), |(k, v)| (k, Some(v)));
-            let decoded_key = urlencoding_decode(key).unwrap_or_else(|| key.to_string());
-            if !decoded_key.is_empty() {
-                let value = value
-                    .map(|v| urlencoding_decode(v).unwrap_or_else(|| v.to_string()))
-                    .unwrap_or_default();
-                match result.entry_mut(&decoded_key) {
-                    Some(values) => values.push(value),
-                    None => result.params.push((decoded_key, vec![value]))
-                }
-            }
+            let value = value
+                .map(|v| form_decode(v).into_owned())
+                .unwrap_or_default();
+            result.push_decoded(form_decode(key), value);
         }
 
         result
     }
 
+    /// Parses a query string like [`parse`](Self::parse), rejecting
+    /// malformed percent-encoding instead of keeping it verbatim.
+    ///
+    /// Use this for untrusted deep links that should fail loudly rather than
+    /// yield a half-decoded value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NavError::InvalidRoute`] when a key or value contains a
+    /// truncated or non-hex `%` escape, or decodes to invalid UTF-8. The
+    /// message carries the byte offset into `query`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_nav_link::{NavError, utils::QueryParams};
+    ///
+    /// let params = QueryParams::parse_strict("q=a+b%21").expect("well-formed");
+    /// assert_eq!(params.get("q"), Some("a b!"));
+    ///
+    /// let err = QueryParams::parse_strict("page=1&q=%zz").unwrap_err();
+    /// assert!(matches!(err, NavError::InvalidRoute(_)));
+    /// ```
+    pub fn parse_strict(query: &str) -> NavResult<Self> {
+        let trimmed = query.trim_start_matches('?');
+        let mut offset = query.len() - trimmed.len();
+        let mut result = Self::default();
+
+        for pair in trimmed.split('&') {
+            let (key, value) = pair
+                .split_once('=')
+                .map_or((pair, None), |(k, v)| (k, Some(v)));
+            let strict = |input: &str, start: usize| {
+                try_decode_with(input, EncodeSet::Form).map_err(|mut err| {
+                    err.offset += start;
+                    NavError::invalid_route(format!("query: {err}"))
+                })
+            };
+            let decoded_key = strict(key, offset)?;
+            let value = value
+                .map(|v| strict(v, offset + key.len() + 1))
+                .transpose()?
+                .unwrap_or_default();
+            result.push_decoded(Cow::Owned(decoded_key), value);
+            offset += pair.len() + 1;
+        }
+
+        Ok(result)
+    }
+
     /// Returns the first value for the given key, if present.
     ///
     /// For query strings with multiple values per key (e.g.
@@ -255,7 +353,10 @@ impl QueryParams {
     /// Returns an empty string if there are no parameters.
     /// Multiple values for the same key are serialized as repeated
     /// `key=value` pairs. Keys appear in insertion order, so the output
-    /// is deterministic.
+    /// is deterministic. Keys and values are escaped with
+    /// [`QueryEncoding::Form`]; use
+    /// [`to_query_string_with`](Self::to_query_string_with) for
+    /// [`QueryEncoding::Component`].
     ///
     /// # Examples
     ///
@@ -270,22 +371,51 @@ impl QueryParams {
     /// ```
     #[must_use]
     pub fn to_query_string(&self) -> String {
+        self.to_query_string_with(QueryEncoding::Form)
+    }
+
+    /// Serializes the parameters like
+    /// [`to_query_string`](Self::to_query_string), escaping keys and values
+    /// with `encoding`.
+    ///
+    /// [`QueryEncoding::Component`] writes spaces as `%20` and leaves `/`,
+    /// `?`, `:`, and `@` readable, for servers that do not apply the
+    /// form-urlencoded `+` convention.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_nav_link::utils::{QueryEncoding, QueryParams};
+    ///
+    /// let mut params = QueryParams::new();
+    /// params.set("q", "a b");
+    /// params.set("next", "/docs?x");
+    /// assert_eq!(
+    ///     params.to_query_string_with(QueryEncoding::Component),
+    ///     "?q=a%20b&next=/docs?x"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_query_string_with(&self, encoding: QueryEncoding) -> String {
+        let set = encoding.encode_set();
         if self.params.is_empty() {
             return String::new();
         }
 
-        let mut pairs: Vec<String> = Vec::with_capacity(self.params.len());
+        let mut result = String::with_capacity(self.params.len() * 16);
         for (key, values) in &self.params {
             for value in values {
-                pairs.push(format!(
+                result.push(if result.is_empty() { '?' } else { '&' });
+                let _ = write!(
+                    result,
                     "{}={}",
-                    urlencoding_encode(key),
-                    urlencoding_encode(value)
-                ));
+                    Encoded::new(key, set),
+                    Encoded::new(value, set)
+                );
             }
         }
 
-        format!("?{}", pairs.join("&"))
+        result
     }
 
     /// Returns the number of unique parameter keys.
@@ -608,4 +738,61 @@ mod tests {
         let all = parsed.get_all("name").unwrap();
         assert_eq!(all, &["hello".to_string(), "world".to_string()]);
     }
+
+    #[test]
+    fn query_params_to_query_string_with_query_component() {
+        let mut params = QueryParams::new();
+        params.set("q", "rust & wasm");
+        params.set("c++", "1+1=2");
+        let qs = params.to_query_string_with(QueryEncoding::Component);
+        assert_eq!(qs, "?q=rust%20%26%20wasm&c%2B%2B=1%2B1%3D2");
+        assert_eq!(QueryParams::parse(&qs), params);
+    }
+
+    #[test]
+    fn query_encodings_escape_pair_delimiters() {
+        let mut params = QueryParams::new();
+        params.set("k", "a&b=c+d#e");
+        for encoding in [QueryEncoding::Form, QueryEncoding::Component] {
+            let qs = params.to_query_string_with(encoding);
+            assert_eq!(QueryParams::parse(&qs), params, "{encoding:?}: {qs}");
+        }
+        assert_eq!(QueryEncoding::default().encode_set(), EncodeSet::Form);
+    }
+
+    #[test]
+    fn query_params_to_query_string_with_form_matches_default() {
+        let params = QueryParams::parse("a=hello+world&b=%2F");
+        assert_eq!(
+            params.to_query_string_with(QueryEncoding::Form),
+            params.to_query_string()
+        );
+    }
+
+    #[test]
+    fn query_params_parse_strict_accepts_well_formed_input() {
+        let strict = QueryParams::parse_strict("?tag=rust&tag=web&q=a+b%21").unwrap();
+        assert_eq!(strict, QueryParams::parse("?tag=rust&tag=web&q=a+b%21"));
+        assert_eq!(strict.get("q"), Some("a b!"));
+    }
+
+    #[test]
+    fn query_params_parse_strict_reports_offset_into_query() {
+        assert_eq!(
+            QueryParams::parse_strict("?page=1&q=%zz"),
+            Err(NavError::invalid_route(
+                "query: invalid hex digits in percent-escape at byte 10"
+            ))
+        );
+        assert_eq!(
+            QueryParams::parse_strict("a=1&%FF=x"),
+            Err(NavError::invalid_route("query: invalid UTF-8 at byte 4"))
+        );
+        assert_eq!(
+            QueryParams::parse_strict("a=%2"),
+            Err(NavError::invalid_route(
+                "query: truncated percent-escape at byte 2"
+            ))
+        );
+    }
 }
@@ -0,0 +1,274 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! RFC 3986 §5 reference resolution.
+
+use super::parts::UrlParts;
+use crate::utils::normalize_path;
+
+/// The five components of a URI reference, split per RFC 3986 Appendix B.
+#[derive(Debug, Default)]
+struct Reference<'a> {
+    scheme:    Option<&'a str>,
+    authority: Option<&'a str>,
+    path:      &'a str,
+    query:     Option<&'a str>,
+    fragment:  Option<&'a str>
+}
+
+impl<'a> Reference<'a> {
+    fn split(reference: &'a str) -> Self {
+        let mut parsed = Self::default();
+
+        let (rest, fragment) = reference
+            .split_once('#')
+            .map_or((reference, None), |(rest, fragment)| (rest, Some(fragment)));
+        parsed.fragment = fragment;
+        let (mut rest, query) = rest
+            .split_once('?')
+            .map_or((rest, None), |(rest, query)| (rest, Some(query)));
+        parsed.query = query;
+
+        if let Some((scheme, after)) = rest.split_once(':')
+            && is_scheme(scheme)
+        {
+            parsed.scheme = Some(scheme);
+            rest = after;
+        }
+        if let Some(after) = rest.strip_prefix("//") {
+            let end = after.find('/').unwrap_or(after.len());
+            let (authority, path) = after.split_at(end);
+            parsed.authority = Some(authority);
+            rest = path;
+        }
+        parsed.path = rest;
+        parsed
+    }
+}
+
+/// `scheme = ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )`. A colon after
+/// anything else belongs to a relative path segment.
+fn is_scheme(candidate: &str) -> bool {
+    let mut chars = candidate.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Removes dot segments with [`normalize_path`], keeping the trailing `/`
+/// that a final `.` or `..` segment implies (`/a/b/..` → `/a/`).
+fn remove_dot_segments(path: &str) -> String {
+    let last = path.rsplit('/').next().unwrap_or_default();
+    if matches!(last, "." | "..") {
+        normalize_path(&format!("{path}/"))
+    } else {
+        normalize_path(path)
+    }
+}
+
+/// Merges a relative-path reference with the base path (RFC 3986 §5.2.3).
+fn merge(base: &UrlParts, path: &str) -> String {
+    if base.host.is_some() && base.path.is_empty() {
+        return format!("/{path}");
+    }
+    base.path.rfind('/').map_or_else(
+        || path.to_string(),
+        |slash| format!("{}{path}", &base.path[..=slash])
+    )
+}
+
+impl UrlParts {
+    /// Resolves `reference` against `base` with the RFC 3986 §5.2 algorithm,
+    /// the way a browser resolves an `href`.
+    ///
+    /// The reference may be absolute (`https://other/x`), scheme-relative
+    /// (`//cdn.example.com/app.js`), root-relative (`/docs`), or relative
+    /// (`../api?v=2#top`, `?page=3`, `#section`). Dot segments are removed
+    /// with [`normalize_path`], which also collapses empty segments
+    /// (`a//b` → `a/b`) where the RFC would keep them. Components are
+    /// copied verbatim, without re-encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_nav_link::utils::UrlParts;
+    ///
+    /// let base = UrlParts::parse("https://example.com/docs/guide/intro?lang=en");
+    ///
+    /// assert_eq!(
+    ///     UrlParts::resolve(&base, "../api?v=2#top").to_string(),
+    ///     "https://example.com/docs/api?v=2#top"
+    /// );
+    /// assert_eq!(
+    ///     UrlParts::resolve(&base, "#setup").to_string(),
+    ///     "https://example.com/docs/guide/intro?lang=en#setup"
+    /// );
+    /// assert_eq!(
+    ///     UrlParts::resolve(&base, "//cdn.example.com/app.js").to_string(),
+    ///     "https://cdn.example.com/app.js"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn resolve(base: &Self, reference: &str) -> Self {
+        let reference = Reference::split(reference);
+        let mut target = Self {
+            fragment: reference.fragment.map(ToString::to_string),
+            ..Self::default()
+        };
+
+        if let Some(scheme) = reference.scheme {
+            target.scheme = Some(scheme.to_string());
+        } else {
+            target.scheme.clone_from(&base.scheme);
+        }
+
+        if reference.scheme.is_some() || reference.authority.is_some() {
+            if let Some(authority) = reference.authority {
+                target.set_authority(authority);
+            }
+            target.path = remove_dot_segments(reference.path);
+            target.query = reference.query.map(ToString::to_string);
+            return target;
+        }
+
+        target.username.clone_from(&base.username);
+        target.password.clone_from(&base.password);
+        target.host.clone_from(&base.host);
+        target.port.clone_from(&base.port);
+        if reference.path.is_empty() {
+            target.path.clone_from(&base.path);
+            target.query = reference
+                .query
+                .map_or_else(|| base.query.clone(), |query| Some(query.to_string()));
+        } else {
+            target.path = if reference.path.starts_with('/') {
+                remove_dot_segments(reference.path)
+            } else {
+                remove_dot_segments(&merge(base, reference.path))
+            };
+            target.query = reference.query.map(ToString::to_string);
+        }
+        target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RFC_BASE: &str = "http://a/b/c/d;p?q";
+
+    fn resolve(reference: &str) -> String {
+        UrlParts::resolve(&UrlParts::parse(RFC_BASE), reference).to_string()
+    }
+
+    /// RFC 3986 §5.4.1.
+    #[test]
+    fn resolve_rfc_normal_examples() {
+        for (reference, expected) in [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("g?y#s", "http://a/b/c/g?y#s"),
+            (";x", "http://a/b/c/;x"),
+            ("g;x", "http://a/b/c/g;x"),
+            ("g;x?y#s", "http://a/b/c/g;x?y#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../", "http://a/"),
+            ("../../g", "http://a/g")
+        ] {
+            assert_eq!(resolve(reference), expected, "reference {reference:?}");
+        }
+    }
+
+    /// RFC 3986 §5.4.2, using the strict reading for `http:g`.
+    #[test]
+    fn resolve_rfc_abnormal_examples() {
+        for (reference, expected) in [
+            ("../../../g", "http://a/g"),
+            ("../../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            (".g", "http://a/b/c/.g"),
+            ("g..", "http://a/b/c/g.."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("./g/.", "http://a/b/c/g/"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g;x=1/./y", "http://a/b/c/g;x=1/y"),
+            ("g;x=1/../y", "http://a/b/c/y"),
+            ("g?y/./x", "http://a/b/c/g?y/./x"),
+            ("g?y/../x", "http://a/b/c/g?y/../x"),
+            ("g#s/./x", "http://a/b/c/g#s/./x"),
+            ("g#s/../x", "http://a/b/c/g#s/../x"),
+            ("http:g", "http:g")
+        ] {
+            assert_eq!(resolve(reference), expected, "reference {reference:?}");
+        }
+    }
+
+    #[test]
+    fn resolve_scheme_relative_reference_replaces_userinfo_and_port() {
+        let base = UrlParts::parse("https://user:pw@example.com:8443/a/b");
+        let resolved = UrlParts::resolve(&base, "//cdn.example.com/./x/../app.js?v=1");
+        assert_eq!(resolved.scheme, Some("https".to_string()));
+        assert_eq!(resolved.username, None);
+        assert_eq!(resolved.port, None);
+        assert_eq!(resolved.to_string(), "https://cdn.example.com/app.js?v=1");
+    }
+
+    #[test]
+    fn resolve_relative_reference_keeps_base_authority() {
+        let base = UrlParts::parse("https://user@example.com:8443/a/b");
+        assert_eq!(
+            UrlParts::resolve(&base, "c").to_string(),
+            "https://user@example.com:8443/a/c"
+        );
+    }
+
+    #[test]
+    fn resolve_against_authority_with_empty_path() {
+        let base = UrlParts::parse("https://example.com");
+        assert_eq!(
+            UrlParts::resolve(&base, "docs").to_string(),
+            "https://example.com/docs"
+        );
+    }
+
+    #[test]
+    fn resolve_against_path_only_base() {
+        let base = UrlParts::parse("/docs/guide/intro");
+        assert_eq!(UrlParts::resolve(&base, "../api").to_string(), "/docs/api");
+        assert_eq!(
+            UrlParts::resolve(&base, "?q=1").to_string(),
+            "/docs/guide/intro?q=1"
+        );
+    }
+
+    #[test]
+    fn resolve_colon_after_non_scheme_is_a_path() {
+        let base = UrlParts::parse("https://example.com/a/");
+        assert_eq!(
+            UrlParts::resolve(&base, "./1:x").to_string(),
+            "https://example.com/a/1:x"
+        );
+        assert_eq!(
+            UrlParts::resolve(&base, "1:x").to_string(),
+            "https://example.com/a/1:x"
+        );
+    }
+}
@@ -13,9 +13,15 @@ mod hooks_route_test {
         #[derive(Clone, PartialEq)]
         struct TestRoute;
         let item = BreadcrumbItem {
-            route:     TestRoute,
-            label:     "/test".to_string(),
-            is_active: true
+            route:         TestRoute,
+            href:          "/".to_string(),
+            path:          "/".to_string(),
+            segment:       String::new(),
+            icon:          None,
+            depth:         0,
+            is_recognized: true,
+            label:         "/test".to_string(),
+            is_active:     true
         };
         assert_eq!(item.label, "/test");
         assert!(item.is_active);
@@ -24,9 +30,15 @@ mod hooks_route_test {
     #[test]
     fn breadcrumb_item_clone() {
         let item1 = BreadcrumbItem {
-            route:     "route1",
-            label:     "label1".to_string(),
-            is_active: false
+            route:         "route1",
+            href:          "/".to_string(),
+            path:          "/".to_string(),
+            segment:       String::new(),
+            icon:          None,
+            depth:         0,
+            is_recognized: true,
+            label:         "label1".to_string(),
+            is_active:     false
         };
         let item2 = item1.clone();
         assert_eq!(item1.label, item2.label);
@@ -35,9 +47,15 @@ mod hooks_route_test {
     #[test]
     fn breadcrumb_item_debug() {
         let item = BreadcrumbItem {
-            route:     "route",
-            label:     "test".to_string(),
-            is_active: true
+            route:         "route",
+            href:          "/".to_string(),
+            path:          "/".to_string(),
+            segment:       String::new(),
+            icon:          None,
+            depth:         0,
+            is_recognized: true,
+            label:         "test".to_string(),
+            is_active:     true
         };
         let debug_str = format!("{item:?}");
         assert!(debug_str.contains("BreadcrumbItem"));
@@ -46,9 +64,15 @@ mod hooks_route_test {
     #[test]
     fn breadcrumb_item_inactive() {
         let item = BreadcrumbItem {
-            route:     "route",
-            label:     "label".to_string(),
-            is_active: false
+            route:         "route",
+            href:          "/".to_string(),
+            path:          "/".to_string(),
+            segment:       String::new(),
+            icon:          None,
+            depth:         0,
+            is_recognized: true,
+            label:         "label".to_string(),
+            is_active:     false
         };
         assert!(!item.is_active);
     }
@@ -56,9 +80,15 @@ mod hooks_route_test {
     #[test]
     fn breadcrumb_item_nested_path() {
         let item = BreadcrumbItem {
-            route:     "route",
-            label:     "/docs/api/v1".to_string(),
-            is_active: true
+            route:         "route",
+            href:          "/".to_string(),
+            path:          "/".to_string(),
+            segment:       String::new(),
+            icon:          None,
+            depth:         0,
+            is_recognized: true,
+            label:         "/docs/api/v1".to_string(),
+            is_active:     true
         };
         assert!(item.label.contains("docs"));
         assert!(item.label.contains("api"));
@@ -67,9 +97,15 @@ mod hooks_route_test {
     #[test]
     fn breadcrumb_item_root_path() {
         let item = BreadcrumbItem {
-            route:     "route",
-            label:     "/".to_string(),
-            is_active: true
+            route:         "route",
+            href:          "/".to_string(),
+            path:          "/".to_string(),
+            segment:       String::new(),
+            icon:          None,
+            depth:         0,
+            is_recognized: true,
+            label:         "/".to_string(),
+            is_active:     true
         };
         assert_eq!(item.label, "/");
     }
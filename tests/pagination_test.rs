@@ -8,7 +8,7 @@
 
 mod pagination_test {
     use yew::prelude::*;
-    use yew_nav_link::components::PaginationProps;
+    use yew_nav_link::{AriaCurrent, components::PaginationProps};
 
     #[test]
     fn pagination_props_default() {
@@ -32,7 +32,9 @@ mod pagination_test {
             siblings:        2,
             show_prev_next:  true,
             show_first_last: true,
-            on_page_change:  Some(on_change)
+            on_page_change:  Some(on_change),
+            href_for:        None,
+            aria_current:    AriaCurrent::Page
         };
 
         assert_eq!(props.current_page, 5);
@@ -53,7 +55,9 @@ mod pagination_test {
             siblings:        1,
             show_prev_next:  true,
             show_first_last: false,
-            on_page_change:  None
+            on_page_change:  None,
+            href_for:        None,
+            aria_current:    AriaCurrent::Page
         };
 
         let props2 = props1.clone();
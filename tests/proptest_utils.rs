@@ -144,7 +144,7 @@ proptest! {
     }
 
     /// `UrlParts::parse` decomposes a structured URL into exactly the pieces
-    /// it was built from.
+    /// it was built from, and `to_string` reassembles the original URL.
     #[test]
     fn url_parts_round_trip(
         scheme in "[a-z]{2,5}",
@@ -172,11 +172,20 @@ proptest! {
         }
 
         let parts = UrlParts::parse(&url);
+        prop_assert_eq!(parts.to_string(), url);
         prop_assert_eq!(parts.scheme.as_deref(), Some(scheme.as_str()));
-        prop_assert_eq!(parts.host.as_deref(), Some(host.as_str()));
-        prop_assert_eq!(parts.port, port.map(|p| p.to_string()));
+        prop_assert_eq!(parts.host.map(|h| h.to_string()), Some(host));
+        prop_assert_eq!(parts.port, port);
         prop_assert_eq!(parts.path, path);
         prop_assert_eq!(parts.query, query);
         prop_assert_eq!(parts.fragment, fragment);
     }
+
+    /// Serializing any parsed input and parsing it again yields the same
+    /// parts.
+    #[test]
+    fn url_parts_serialize_is_a_parse_fixed_point(input in ".*") {
+        let parts = UrlParts::parse(&input);
+        prop_assert_eq!(UrlParts::parse(&parts.to_string()), parts);
+    }
 }
@@ -0,0 +1,358 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Native tests for server-side rendering: each test renders a small app
+//! under `StaticRouter` at a given URL through `yew::ServerRenderer` and
+//! asserts on the emitted HTML.
+
+#![cfg(all(test, feature = "ssr", not(target_arch = "wasm32")))]
+
+mod ssr_test {
+    use yew::prelude::*;
+    use yew_nav_link::{
+        AriaCurrent, BreadcrumbSchema, Breadcrumbs, GroupRoute, Match, NavBadge, NavDivider,
+        NavDropdown, NavDropdownDivider, NavDropdownItem, NavEventsProvider, NavGroup, NavHeader,
+        NavIcon, NavIconSize, NavItem, NavLink, NavLinkWithIcon, NavList, NavTab, NavTabPanel,
+        NavTabs, NavText, NavigationHistoryProvider, PageItem, PageLink, Pagination, StaticRouter,
+        nav_link, use_breadcrumbs, use_is_active, use_query_params, use_route_info
+    };
+    use yew_router::prelude::*;
+
+    #[derive(Clone, PartialEq, Debug, Routable)]
+    enum Route {
+        #[at("/")]
+        Home,
+        #[at("/docs")]
+        Docs,
+        #[at("/docs/api")]
+        Api,
+        #[at("/about")]
+        About,
+        #[not_found]
+        #[at("/404")]
+        NotFound
+    }
+
+    #[derive(Properties)]
+    struct AppProps {
+        url:      &'static str,
+        basename: Option<&'static str>,
+        body:     fn() -> Html
+    }
+
+    /// Server rendering builds the props once and never diffs them, so
+    /// comparing the URL is enough; function pointers have no meaningful
+    /// equality.
+    impl PartialEq for AppProps {
+        fn eq(&self, other: &Self) -> bool {
+            self.url == other.url && self.basename == other.basename
+        }
+    }
+
+    #[component]
+    fn App(props: &AppProps) -> Html {
+        html! {
+            <StaticRouter url={props.url} basename={props.basename.map(AttrValue::from)}>
+                { (props.body)() }
+            </StaticRouter>
+        }
+    }
+
+    /// Renders `body` under a `StaticRouter` at `url`, without hydration
+    /// markers.
+    fn render_at(url: &'static str, basename: Option<&'static str>, body: fn() -> Html) -> String {
+        futures::executor::block_on(
+            yew::ServerRenderer::<App>::with_props(move || AppProps {
+                url,
+                basename,
+                body
+            })
+            .hydratable(false)
+            .render()
+        )
+    }
+
+    fn render(url: &'static str, body: fn() -> Html) -> String {
+        render_at(url, None, body)
+    }
+
+    fn menu() -> Html {
+        html! {
+            <nav>
+                <NavLink<Route> to={Route::Home}>{ "Home" }</NavLink<Route>>
+                <NavLink<Route> to={Route::Docs} partial=true>{ "Docs" }</NavLink<Route>>
+                <NavLink<Route> to={Route::About}>{ "About" }</NavLink<Route>>
+            </nav>
+        }
+    }
+
+    #[test]
+    fn nav_link_marks_the_exact_route_active() {
+        let html = render("/about", menu);
+        assert!(
+            html.contains(r#"<a href="/" class="nav-link">Home</a>"#),
+            "{html}"
+        );
+        assert!(
+            html.contains(r#"<a href="/docs" class="nav-link">Docs</a>"#),
+            "{html}"
+        );
+        assert!(
+            html.contains(
+                r#"<a href="/about" aria-current="page" class="nav-link active">About</a>"#
+            ),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn nav_link_partial_match_covers_nested_routes() {
+        let html = render("/docs/api", menu);
+        assert!(
+            html.contains(
+                r#"<a href="/docs" aria-current="page" class="nav-link active">Docs</a>"#
+            ),
+            "{html}"
+        );
+        assert!(
+            html.contains(r#"<a href="/" class="nav-link">Home</a>"#),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn nav_link_uses_custom_classes_and_aria_current_token() {
+        let html = render("/docs", || {
+            html! {
+                <NavLink<Route>
+                    to={Route::Docs}
+                    class="step"
+                    active_class="step-current"
+                    aria_current={AriaCurrent::Step}
+                >
+                    { "Docs" }
+                </NavLink<Route>>
+            }
+        });
+        assert_eq!(
+            html,
+            r#"<a href="/docs" aria-current="step" class="step step-current">Docs</a>"#
+        );
+    }
+
+    #[test]
+    fn nav_link_hrefs_carry_the_basename() {
+        let html = render_at("/app/docs", Some("/app"), menu);
+        assert!(
+            html.contains(r#"<a href="/app/" class="nav-link">Home</a>"#),
+            "{html}"
+        );
+        assert!(
+            html.contains(
+                r#"<a href="/app/docs" aria-current="page" class="nav-link active">Docs</a>"#
+            ),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn nav_link_function_syntax_renders_the_same_anchor() {
+        let html = render("/docs/api", || {
+            nav_link(Route::Docs, "Docs", Match::Partial)
+        });
+        assert_eq!(
+            html,
+            r#"<a href="/docs" aria-current="page" class="nav-link active">Docs</a>"#
+        );
+    }
+
+    #[test]
+    fn nav_group_with_declared_routes_marks_only_the_deepest_link() {
+        let html = render("/docs/api", || {
+            let routes = vec![
+                GroupRoute::new(&Route::Docs, Match::Partial),
+                GroupRoute::new(&Route::Api, Match::Partial),
+            ];
+            html! {
+                <NavGroup {routes}>
+                    <NavLink<Route> to={Route::Docs} partial=true>{ "Docs" }</NavLink<Route>>
+                    <NavLink<Route> to={Route::Api} partial=true>{ "API" }</NavLink<Route>>
+                </NavGroup>
+            }
+        });
+        assert_eq!(
+            html,
+            concat!(
+                r#"<a href="/docs" class="nav-link active-trail">Docs</a>"#,
+                r#"<a href="/docs/api" aria-current="page" class="nav-link active">API</a>"#
+            )
+        );
+    }
+
+    #[test]
+    fn unmatched_url_renders_every_link_inactive() {
+        let html = render("/missing", menu);
+        assert!(!html.contains("active"), "{html}");
+        assert!(!html.contains("aria-current"), "{html}");
+    }
+
+    #[test]
+    fn active_state_hooks_read_the_static_url() {
+        #[component]
+        fn Probe() -> Html {
+            let docs = use_is_active(Route::Docs, Match::Exact);
+            let info = use_route_info::<Route>();
+            html! { <p>{ format!("{docs}|{}|{:?}", info.route_path, info.route) }</p> }
+        }
+        assert_eq!(
+            render("/docs", || html! { <Probe /> }),
+            "<p>true|/docs|Some(Docs)</p>"
+        );
+    }
+
+    #[test]
+    fn query_params_are_read_from_the_url() {
+        #[component]
+        fn Probe() -> Html {
+            let params = use_query_params();
+            html! {
+                <p>{ format!("{:?}|{:?}", params.get("page"), params.get("tag")) }</p>
+            }
+        }
+        assert_eq!(
+            render("/docs?page=2&tag=a%20b", || html! { <Probe /> }),
+            r#"<p>Some("2")|Some("a b")</p>"#
+        );
+    }
+
+    #[test]
+    fn use_breadcrumbs_builds_the_trail_of_the_url() {
+        #[component]
+        fn Probe() -> Html {
+            let trail = use_breadcrumbs::<Route>()
+                .iter()
+                .map(|item| format!("{}{}", item.href, if item.is_active { "*" } else { "" }))
+                .collect::<Vec<_>>()
+                .join(" ");
+            html! { <p>{ trail }</p> }
+        }
+        assert_eq!(
+            render("/docs/api", || html! { <Probe /> }),
+            "<p>/ /docs /docs/api*</p>"
+        );
+    }
+
+    #[test]
+    fn breadcrumbs_mark_the_current_crumb() {
+        let html = render("/docs/api", || {
+            html! {
+                <Breadcrumbs<Route>
+                    ancestor_aria_current={Some(AriaCurrent::Location)}
+                    schema={BreadcrumbSchema::JsonLd}
+                />
+            }
+        });
+        assert!(
+            html.contains(r#"<a href="/docs" aria-current="location" class="nav-link">"#),
+            "{html}"
+        );
+        assert!(
+            html.contains(r#"<a href="/docs/api" aria-current="page" class="nav-link active">"#),
+            "{html}"
+        );
+        assert!(
+            html.contains(r#"<script type="application/ld+json">"#),
+            "{html}"
+        );
+        assert!(html.contains("BreadcrumbList"), "{html}");
+    }
+
+    #[test]
+    fn pagination_marks_the_current_page_and_prefixes_hrefs() {
+        let html = render_at("/app/docs?page=3", Some("/app"), || {
+            html! {
+                <Pagination
+                    current_page={3}
+                    total_pages={5}
+                    href_for={Callback::from(|page: u32| AttrValue::from(format!("/docs?page={page}")))}
+                />
+            }
+        });
+        assert!(
+            html.contains(r#"<a href="/app/docs?page=3" aria-current="page">3</a>"#),
+            "{html}"
+        );
+        assert!(
+            html.contains(r#"<a href="/app/docs?page=2" aria-label="Previous page">"#),
+            "{html}"
+        );
+        assert_eq!(html.matches("aria-current").count(), 1, "{html}");
+    }
+
+    #[test]
+    fn every_component_renders_on_the_server() {
+        let html = render("/docs", || {
+            html! {
+                <NavEventsProvider<Route>>
+                    <NavigationHistoryProvider<Route>>
+                        <NavList aria_label="Main">
+                            <NavHeader text="Sections" />
+                            <NavItem>
+                                <NavLink<Route> to={Route::Docs}>{ "Docs" }</NavLink<Route>>
+                            </NavItem>
+                            <NavDivider />
+                            <NavItem>
+                                <NavLinkWithIcon icon={NavIconSize::Small}>
+                                    <NavIcon name="book" />
+                                    <NavText text="Guide" />
+                                </NavLinkWithIcon>
+                                <NavBadge variant="primary">{ "new" }</NavBadge>
+                            </NavItem>
+                        </NavList>
+                        <NavTabs>
+                            <NavTab active=true id="t1" panel_id="p1">{ "One" }</NavTab>
+                            <NavTab active=false id="t2" panel_id="p2">{ "Two" }</NavTab>
+                        </NavTabs>
+                        <NavTabPanel id="p1" labelled_by="t1" hidden=false>{ "Panel" }</NavTabPanel>
+                        <NavDropdown toggle_text="More">
+                            <NavDropdownItem>{ "Item" }</NavDropdownItem>
+                            <NavDropdownDivider />
+                        </NavDropdown>
+                        <ul>
+                            <PageItem active=true aria_current={AriaCurrent::Step}>
+                                <PageLink href={None::<AttrValue>}>{ "1" }</PageLink>
+                            </PageItem>
+                        </ul>
+                        <NavGroup>
+                            <NavLink<Route> to={Route::About}>{ "About" }</NavLink<Route>>
+                        </NavGroup>
+                    </NavigationHistoryProvider<Route>>
+                </NavEventsProvider<Route>>
+            }
+        });
+        assert!(
+            html.contains(
+                r#"<a href="/docs" aria-current="page" class="nav-link active">Docs</a>"#
+            ),
+            "{html}"
+        );
+        assert!(
+            html.contains(r#"<a href="/about" class="nav-link">About</a>"#),
+            "{html}"
+        );
+        assert!(html.contains(r#"aria-selected="true""#), "{html}");
+        assert!(html.contains("More"), "{html}");
+        assert!(html.contains("Panel"), "{html}");
+        assert!(html.contains(r#"aria-current="step""#), "{html}");
+    }
+
+    #[test]
+    fn not_found_route_is_recognized() {
+        #[component]
+        fn Probe() -> Html {
+            let info = use_route_info::<Route>();
+            html! { <p>{ info.is_not_found.to_string() }</p> }
+        }
+        assert_eq!(render("/nowhere", || html! { <Probe /> }), "<p>true</p>");
+    }
+}
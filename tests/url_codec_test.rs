@@ -6,7 +6,10 @@
 
 #![cfg(all(test, not(target_arch = "wasm32")))]
 
-use yew_nav_link::utils::{urlencoding_decode, urlencoding_encode};
+use yew_nav_link::utils::{
+    DecodeErrorKind, EncodeSet, decode_with, encode_with, percent_decode, try_percent_decode,
+    urlencoding_decode, urlencoding_encode
+};
 
 #[test]
 fn urlencoding_decode_simple() {
@@ -99,3 +102,27 @@ fn urlencoding_roundtrip() {
 fn urlencoding_decode_multiple_pluses() {
     assert_eq!(urlencoding_decode("a+++b"), Some("a   b".to_string()));
 }
+
+#[test]
+fn encode_with_sets_differ_on_space() {
+    assert_eq!(encode_with("a b", EncodeSet::Form), "a+b");
+    assert_eq!(encode_with("a b", EncodeSet::PathSegment), "a%20b");
+    assert_eq!(encode_with("a b", EncodeSet::QueryComponent), "a%20b");
+}
+
+#[test]
+fn decode_with_path_segment_matches_percent_decode() {
+    let input = "/c++/hello%20world";
+    assert_eq!(
+        decode_with(input, EncodeSet::PathSegment),
+        percent_decode(input)
+    );
+}
+
+#[test]
+fn try_percent_decode_rejects_what_percent_decode_keeps() {
+    assert_eq!(percent_decode("100%zz"), Some("100%zz".to_string()));
+    let err = try_percent_decode("100%zz").unwrap_err();
+    assert_eq!(err.offset, 3);
+    assert_eq!(err.kind, DecodeErrorKind::InvalidHex);
+}
@@ -36,3 +36,15 @@ mod tabs;
 
 #[path = "wasm/hooks.rs"]
 mod hooks;
+
+#[path = "wasm/basename.rs"]
+mod basename;
+
+#[path = "wasm/breadcrumbs.rs"]
+mod breadcrumbs;
+
+#[path = "wasm/events.rs"]
+mod events;
+
+#[path = "wasm/hydration.rs"]
+mod hydration;
@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Browser tests for apps deployed under a router basename: `use_basename`,
+//! breadcrumb hrefs, `Navigation::href`, and `Pagination` links all carry
+//! the `/app/v2` prefix.
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::Element;
+use yew::prelude::*;
+use yew_nav_link::{
+    Pagination, use_basename, use_breadcrumbs, use_navigation, utils::QueryParams
+};
+use yew_router::prelude::*;
+
+use super::common::{TestRoute, document, fresh_root, navigate, wait_for_render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[function_component]
+fn Probe() -> Html {
+    let basename = use_basename().unwrap_or_else(|| "none".to_string());
+    let nav = use_navigation::<TestRoute>();
+    let crumbs = use_breadcrumbs::<TestRoute>();
+    let mut params = QueryParams::new();
+    params.set("page", "2");
+
+    html! {
+        <>
+            <span id="basename">{ basename }</span>
+            <a id="nav-href" href={nav.href(&TestRoute::Docs)}>{ "docs" }</a>
+            <a id="nav-query" href={nav.href_with_query(&TestRoute::Docs, &params)}>
+                { "docs p2" }
+            </a>
+            <ol>
+                { for crumbs.iter().map(|crumb| html! {
+                    <li><a class="crumb" href={crumb.href.clone()}>{ &crumb.label }</a></li>
+                }) }
+            </ol>
+            <Pagination
+                current_page={1}
+                total_pages={3}
+                href_for={Callback::from(|page: u32| AttrValue::from(format!("/docs?page={page}")))}
+            />
+        </>
+    }
+}
+
+#[function_component]
+fn BasenameApp() -> Html {
+    html! {
+        <BrowserRouter basename="/app/v2">
+            <Probe />
+        </BrowserRouter>
+    }
+}
+
+fn attr(selector: &str, name: &str) -> Option<String> {
+    document()
+        .query_selector(selector)
+        .unwrap()
+        .unwrap_or_else(|| panic!("{selector} should render"))
+        .get_attribute(name)
+}
+
+fn render_at(path: &str) {
+    navigate(path);
+    let root = fresh_root();
+    yew::Renderer::<BasenameApp>::with_root(root).render();
+}
+
+#[wasm_bindgen_test]
+async fn use_basename_reports_router_basename() {
+    render_at("/app/v2/docs");
+    wait_for_render().await;
+
+    let text = document()
+        .get_element_by_id("basename")
+        .and_then(|el| el.text_content());
+    assert_eq!(text.as_deref(), Some("/app/v2"));
+}
+
+#[wasm_bindgen_test]
+async fn navigation_href_prepends_basename() {
+    render_at("/app/v2/docs");
+    wait_for_render().await;
+
+    assert_eq!(attr("#nav-href", "href").as_deref(), Some("/app/v2/docs"));
+    assert_eq!(
+        attr("#nav-query", "href").as_deref(),
+        Some("/app/v2/docs?page=2")
+    );
+}
+
+#[wasm_bindgen_test]
+async fn breadcrumb_hrefs_prepend_basename() {
+    render_at("/app/v2/docs/api");
+    wait_for_render().await;
+
+    let list = document().query_selector_all("a.crumb").unwrap();
+    let hrefs: Vec<String> = (0..list.length())
+        .filter_map(|index| list.item(index))
+        .filter_map(|node| node.dyn_into::<Element>().ok()?.get_attribute("href"))
+        .collect();
+    assert_eq!(hrefs, ["/app/v2/", "/app/v2/docs", "/app/v2/docs/api"]);
+}
+
+#[wasm_bindgen_test]
+async fn pagination_links_prepend_basename() {
+    render_at("/app/v2/docs");
+    wait_for_render().await;
+
+    assert_eq!(
+        attr(".pagination a[aria-label='Next page']", "href").as_deref(),
+        Some("/app/v2/docs?page=2")
+    );
+    assert_eq!(
+        attr(".pagination a[aria-current='page']", "href").as_deref(),
+        Some("/app/v2/docs?page=1")
+    );
+
+    let previous = ".pagination a[aria-label='Previous page']";
+    assert_eq!(attr(previous, "href"), None);
+    assert_eq!(attr(previous, "aria-disabled").as_deref(), Some("true"));
+}
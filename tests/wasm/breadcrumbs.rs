@@ -0,0 +1,324 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Browser tests for the `Breadcrumbs` component: `NavLink` crumbs with
+//! `aria-current` on the last one (and optionally on its ancestors), collapse
+//! and expansion past `max_items`, schema.org microdata / JSON-LD output,
+//! plain-text crumbs with icons for unrecognized path prefixes, and crumb
+//! links staying out of an enclosing `NavGroup`.
+
+use std::rc::Rc;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+use yew_nav_link::{
+    AriaCurrent, BreadcrumbContext, BreadcrumbSchema, Breadcrumbs, NavGroup, NavLink,
+    RouteLabelProvider, RouteLabelProviderContext
+};
+use yew_router::prelude::*;
+
+use super::common::{TestRoute, document, fresh_root, navigate, wait_for_render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Properties, PartialEq)]
+struct AppProps {
+    max_items: Option<usize>,
+    schema:    BreadcrumbSchema
+}
+
+#[function_component]
+fn App(props: &AppProps) -> Html {
+    html! {
+        <BrowserRouter>
+            <Breadcrumbs<TestRoute>
+                separator={html! { "›" }}
+                max_items={props.max_items}
+                schema={props.schema}
+                base_url="https://example.com"
+            />
+        </BrowserRouter>
+    }
+}
+
+fn render(path: &str, max_items: Option<usize>, schema: BreadcrumbSchema) {
+    navigate(path);
+    let root = fresh_root();
+    yew::Renderer::<App>::with_root_and_props(
+        root,
+        AppProps {
+            max_items,
+            schema
+        }
+    )
+    .render();
+}
+
+fn crumb_labels() -> Vec<String> {
+    let list = document()
+        .query_selector_all("nav.breadcrumbs li.breadcrumb-item")
+        .unwrap();
+    (0..list.length())
+        .filter_map(|index| list.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .map(|li| li.inner_text())
+        .collect()
+}
+
+#[wasm_bindgen_test]
+async fn renders_nav_links_and_marks_last_crumb_current() {
+    render("/docs/api", None, BreadcrumbSchema::None);
+    wait_for_render().await;
+
+    let nav = document()
+        .query_selector("nav.breadcrumbs")
+        .unwrap()
+        .expect("breadcrumbs should render");
+    assert_eq!(
+        nav.get_attribute("aria-label").as_deref(),
+        Some("breadcrumb")
+    );
+    assert_eq!(crumb_labels(), ["/", "›/docs", "›/docs/api"]);
+
+    let current = document()
+        .query_selector_all("li.breadcrumb-item a[aria-current='page']")
+        .unwrap();
+    assert_eq!(current.length(), 1);
+    let current = current.item(0).unwrap().dyn_into::<HtmlElement>().unwrap();
+    assert_eq!(current.get_attribute("href").as_deref(), Some("/docs/api"));
+}
+
+#[function_component]
+fn LocationApp() -> Html {
+    html! {
+        <BrowserRouter>
+            <Breadcrumbs<TestRoute>
+                aria_current={AriaCurrent::Step}
+                ancestor_aria_current={Some(AriaCurrent::Location)}
+            />
+        </BrowserRouter>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn ancestor_crumbs_can_carry_their_own_aria_current_token() {
+    navigate("/docs/api");
+    yew::Renderer::<LocationApp>::with_root(fresh_root()).render();
+    wait_for_render().await;
+
+    let tokens: Vec<_> = {
+        let links = document()
+            .query_selector_all("li.breadcrumb-item a")
+            .unwrap();
+        (0..links.length())
+            .filter_map(|index| links.item(index))
+            .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+            .map(|link| link.get_attribute("aria-current"))
+            .collect()
+    };
+    assert_eq!(
+        tokens,
+        [
+            Some("location".to_string()),
+            Some("location".to_string()),
+            Some("step".to_string())
+        ]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn collapses_middle_crumbs_until_expanded() {
+    render("/docs/api", Some(2), BreadcrumbSchema::None);
+    wait_for_render().await;
+
+    assert_eq!(crumb_labels(), ["/", "›…", "›/docs/api"]);
+
+    document()
+        .query_selector(".breadcrumb-ellipsis button")
+        .unwrap()
+        .expect("ellipsis button should render")
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+    wait_for_render().await;
+
+    assert_eq!(crumb_labels(), ["/", "›/docs", "›/docs/api"]);
+}
+
+#[wasm_bindgen_test]
+async fn navigating_collapses_an_expanded_trail_again() {
+    render("/docs/api", Some(2), BreadcrumbSchema::None);
+    wait_for_render().await;
+    document()
+        .query_selector(".breadcrumb-ellipsis button")
+        .unwrap()
+        .expect("ellipsis button should render")
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .click();
+    wait_for_render().await;
+    assert_eq!(crumb_labels(), ["/", "›/docs", "›/docs/api"]);
+
+    navigate("/docs");
+    wait_for_render().await;
+    assert_eq!(crumb_labels(), ["/", "›/docs"]);
+
+    navigate("/docs/api");
+    wait_for_render().await;
+    assert_eq!(
+        crumb_labels(),
+        ["/", "›…", "›/docs/api"],
+        "expansion does not outlive the trail it was made on"
+    );
+}
+
+#[wasm_bindgen_test]
+async fn emits_microdata() {
+    render("/docs", None, BreadcrumbSchema::Microdata);
+    wait_for_render().await;
+
+    let list = document()
+        .query_selector("ol[itemtype='https://schema.org/BreadcrumbList']")
+        .unwrap()
+        .expect("list should carry BreadcrumbList microdata");
+    assert!(list.has_attribute("itemscope"));
+
+    let positions = document()
+        .query_selector_all("li[itemprop='itemListElement'] meta[itemprop='position']")
+        .unwrap();
+    assert_eq!(positions.length(), 2);
+
+    let item = document()
+        .query_selector("li:last-child link[itemprop='item']")
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        item.get_attribute("href").as_deref(),
+        Some("https://example.com/docs")
+    );
+}
+
+#[wasm_bindgen_test]
+async fn emits_json_ld_for_collapsed_crumbs_too() {
+    render("/docs/api", Some(2), BreadcrumbSchema::JsonLd);
+    wait_for_render().await;
+
+    let script = document()
+        .query_selector("nav.breadcrumbs script[type='application/ld+json']")
+        .unwrap()
+        .expect("JSON-LD script should render");
+    let json = script.text_content().unwrap_or_default();
+    assert!(json.contains(r#""@type":"BreadcrumbList""#));
+    assert!(json.contains(r#""position":2,"name":"/docs","item":"https://example.com/docs""#));
+    assert!(json.contains(r#""position":3"#));
+}
+
+#[derive(Clone, PartialEq, Debug, Routable)]
+enum UserRoute {
+    #[at("/")]
+    Home,
+    #[at("/users/:id")]
+    User { id: u32 }
+}
+
+struct UserLabels;
+
+impl RouteLabelProvider<UserRoute> for UserLabels {
+    fn label(&self, crumb: &BreadcrumbContext<UserRoute>) -> Option<String> {
+        match crumb.route.as_ref() {
+            Some(UserRoute::Home) => Some("Home".into()),
+            Some(UserRoute::User {
+                id
+            }) => Some(format!("User {id}")),
+            None => Some("Users".into())
+        }
+    }
+
+    fn icon(&self, crumb: &BreadcrumbContext<UserRoute>) -> Option<AttrValue> {
+        (crumb.depth == 0).then_some(AttrValue::Static("home"))
+    }
+}
+
+#[function_component]
+fn UserApp() -> Html {
+    let context = use_memo((), |()| RouteLabelProviderContext::new(Rc::new(UserLabels)));
+    html! {
+        <BrowserRouter>
+            <ContextProvider<RouteLabelProviderContext<UserRoute>> context={(*context).clone()}>
+                <Breadcrumbs<UserRoute> schema={BreadcrumbSchema::Microdata} />
+            </ContextProvider<RouteLabelProviderContext<UserRoute>>>
+        </BrowserRouter>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn unrecognized_crumbs_render_as_text_and_icons_render() {
+    navigate("/users/7");
+    let root = fresh_root();
+    yew::Renderer::<UserApp>::with_root(root).render();
+    wait_for_render().await;
+
+    assert_eq!(crumb_labels(), ["homeHome", "/Users", "/User 7"]);
+
+    let links = document()
+        .query_selector_all("li.breadcrumb-item a")
+        .unwrap();
+    assert_eq!(links.length(), 2, "only recognized crumbs link");
+
+    let text = document()
+        .query_selector("li.breadcrumb-item .breadcrumb-text")
+        .unwrap()
+        .expect("unrecognized crumb should render as text");
+    assert_eq!(text.text_content().as_deref(), Some("Users"));
+    assert!(
+        document()
+            .query_selector("li:nth-child(2) link[itemprop='item']")
+            .unwrap()
+            .is_none(),
+        "unrecognized crumb has no item URL"
+    );
+
+    let icon = document()
+        .query_selector("li.breadcrumb-item:first-child .nav-icon")
+        .unwrap()
+        .expect("icon should render");
+    assert_eq!(icon.text_content().as_deref(), Some("home"));
+}
+
+#[function_component]
+fn GroupedLayoutApp() -> Html {
+    html! {
+        <BrowserRouter>
+            <NavGroup>
+                <aside>
+                    <NavLink<TestRoute> to={TestRoute::Docs} partial=true>
+                        { "Docs" }
+                    </NavLink<TestRoute>>
+                </aside>
+                <Breadcrumbs<TestRoute> />
+            </NavGroup>
+        </BrowserRouter>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn crumb_links_stay_out_of_an_enclosing_nav_group() {
+    navigate("/docs/api");
+    let root = fresh_root();
+    yew::Renderer::<GroupedLayoutApp>::with_root(root).render();
+    wait_for_render().await;
+    wait_for_render().await;
+
+    let sidebar = document()
+        .query_selector("aside a")
+        .unwrap()
+        .expect("sidebar link should render");
+    assert_eq!(sidebar.class_name(), "nav-link active");
+    let current = document()
+        .query_selector("nav.breadcrumbs a[aria-current='page']")
+        .unwrap()
+        .expect("last crumb should link");
+    assert_eq!(current.class_name(), "nav-link active");
+}
@@ -0,0 +1,163 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Browser tests for the navigation event bus: `NavLink` and `Navigation`
+//! publish through a `NavEventsProvider`, listeners registered with
+//! `use_nav_event_listener` receive every event in order, and a nested
+//! `NavigationHistoryProvider` records the same kind of change.
+
+use std::cell::RefCell;
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::HtmlElement;
+use yew::prelude::*;
+use yew_nav_link::{
+    NavEvent, NavEventsProvider, NavLink, NavigationHistoryProvider, use_history_stack,
+    use_nav_event_listener, use_navigation
+};
+use yew_router::prelude::*;
+
+use super::common::{TestRoute, document, fresh_root, navigate, wait_for_render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+thread_local! {
+    static LOG: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn take_log() -> Vec<String> {
+    LOG.with(|log| log.borrow_mut().drain(..).collect())
+}
+
+#[function_component]
+fn Recorder() -> Html {
+    use_nav_event_listener::<TestRoute, _>(|event| {
+        let entry = match event {
+            NavEvent::RouteChanged {
+                from,
+                to,
+                kind
+            } => format!("changed {from:?}->{to:?} {kind:?}"),
+            NavEvent::LinkClicked {
+                target,
+                modifier
+            } => format!("clicked {target:?} {modifier}"),
+            other => format!("{other:?}")
+        };
+        LOG.with(|log| log.borrow_mut().push(entry));
+    });
+    let nav = use_navigation::<TestRoute>();
+    let back = nav.go_back.reform(|_: MouseEvent| ());
+    html! {
+        <>
+            <NavLink<TestRoute> to={TestRoute::Docs}>{ "Docs" }</NavLink<TestRoute>>
+            <button id="back" onclick={back}>{ "back" }</button>
+        </>
+    }
+}
+
+#[function_component]
+fn EventsApp() -> Html {
+    html! {
+        <BrowserRouter>
+            <NavEventsProvider<TestRoute>>
+                <Recorder />
+            </NavEventsProvider<TestRoute>>
+        </BrowserRouter>
+    }
+}
+
+fn click(element: &web_sys::Element) {
+    element.dyn_ref::<HtmlElement>().unwrap().click();
+}
+
+#[wasm_bindgen_test]
+async fn link_clicks_and_route_changes_reach_listeners() {
+    navigate("/");
+    take_log();
+    let root = fresh_root();
+    yew::Renderer::<EventsApp>::with_root(root.clone()).render();
+    wait_for_render().await;
+    assert_eq!(take_log(), ["changed None->Some(Home) Push"]);
+
+    click(&root.get_elements_by_tag_name("a").item(0).unwrap());
+    wait_for_render().await;
+    assert_eq!(
+        take_log(),
+        ["clicked Docs false", "changed Some(Home)->Some(Docs) Push"]
+    );
+
+    click(&document().get_element_by_id("back").unwrap());
+    wait_for_render().await;
+    assert_eq!(take_log(), ["changed Some(Docs)->Some(Home) Pop"]);
+
+    root.remove();
+}
+
+#[function_component]
+fn StackProbe() -> Html {
+    let nav = use_navigation::<TestRoute>();
+    let stack: Vec<String> = use_history_stack::<TestRoute>()
+        .iter()
+        .map(|entry| format!("{:?}:{:?}", entry.route, entry.direction))
+        .collect();
+    html! {
+        <p id="stack">{ format!("{}|{}", nav.can_go_back(), stack.join(",")) }</p>
+    }
+}
+
+#[function_component]
+fn EventsAndHistoryApp() -> Html {
+    html! {
+        <BrowserRouter>
+            <NavEventsProvider<TestRoute>>
+                <NavigationHistoryProvider<TestRoute>>
+                    <Recorder />
+                    <StackProbe />
+                </NavigationHistoryProvider<TestRoute>>
+            </NavEventsProvider<TestRoute>>
+        </BrowserRouter>
+    }
+}
+
+fn stack_text() -> String {
+    document()
+        .get_element_by_id("stack")
+        .expect("stack probe should render")
+        .text_content()
+        .unwrap_or_default()
+}
+
+#[wasm_bindgen_test]
+async fn events_and_history_agree_on_going_back_to_the_first_page() {
+    navigate("/");
+    take_log();
+    let root = fresh_root();
+    yew::Renderer::<EventsAndHistoryApp>::with_root(root.clone()).render();
+    wait_for_render().await;
+    wait_for_render().await;
+    assert_eq!(take_log(), ["changed None->Some(Home) Push"]);
+    assert_eq!(stack_text(), "false|Home:Push");
+
+    click(&root.get_elements_by_tag_name("a").item(0).unwrap());
+    wait_for_render().await;
+    wait_for_render().await;
+    assert_eq!(
+        take_log(),
+        ["clicked Docs false", "changed Some(Home)->Some(Docs) Push"]
+    );
+    assert_eq!(stack_text(), "true|Home:Push,Docs:Push");
+
+    click(&document().get_element_by_id("back").unwrap());
+    wait_for_render().await;
+    wait_for_render().await;
+    assert_eq!(take_log(), ["changed Some(Docs)->Some(Home) Pop"]);
+    assert_eq!(
+        stack_text(),
+        "false|Home:Pop",
+        "the initial page has no history id, yet both report a pop"
+    );
+
+    root.remove();
+}
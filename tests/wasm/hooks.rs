@@ -10,8 +10,11 @@ use std::rc::Rc;
 use wasm_bindgen_test::*;
 use yew::prelude::*;
 use yew_nav_link::{
-    BreadcrumbLabelProvider, BreadcrumbLabelProviderContext, use_breadcrumbs, use_is_active,
-    use_is_exact_active, use_is_partial_active, use_query_params, use_route_info
+    AsyncBreadcrumbLabelProvider, BreadcrumbConfig, BreadcrumbContext, BreadcrumbLabelProvider,
+    BreadcrumbLabelProviderContext, FnLabelProvider, LabelFuture, MapLabelProvider, Match,
+    RouteLabelProviderContext, use_active_index, use_active_route_among, use_breadcrumbs,
+    use_is_active, use_is_exact_active, use_is_partial_active, use_query_params, use_route_info,
+    use_route_param, utils::PathPattern
 };
 use yew_router::prelude::*;
 
@@ -22,12 +25,19 @@ wasm_bindgen_test_configure!(run_in_browser);
 #[function_component]
 fn ActiveProbe() -> Html {
     let exact_docs = use_is_exact_active(TestRoute::Docs);
-    let active_api = use_is_active(TestRoute::DocsApi);
+    let active_api = use_is_active(TestRoute::DocsApi, Match::Exact);
     let partial_docs = use_is_partial_active(TestRoute::Docs);
     let partial_home = use_is_partial_active(TestRoute::Home);
+    let partial_api = use_is_active(TestRoute::DocsApi, Match::Partial);
+    let tabs = [TestRoute::Home, TestRoute::Docs, TestRoute::About];
+    let tab = use_active_index(&tabs);
+    let sidebar = [TestRoute::Docs, TestRoute::DocsApi];
+    let item = use_active_route_among(&sidebar);
     html! {
         <p id="probe">
-            { format!("{exact_docs}|{active_api}|{partial_docs}|{partial_home}") }
+            { format!(
+                "{exact_docs}|{active_api}|{partial_docs}|{partial_home}|{partial_api}|{tab:?}|{item:?}"
+            ) }
         </p>
     }
 }
@@ -58,21 +68,24 @@ async fn active_hooks_reflect_a_nested_route() {
 
     assert_eq!(
         probe_text(),
-        "false|true|true|false",
-        "exact(Docs)|active(DocsApi)|partial(Docs)|partial(Home) at /docs/api"
+        "false|true|true|false|true|Some(1)|Some(DocsApi)",
+        "exact(Docs)|active(DocsApi)|partial(Docs)|partial(Home)|partial(DocsApi)|tab|sidebar at /docs/api"
     );
 }
 
 #[function_component]
 fn RouteInfoProbe() -> Html {
-    let route = use_route_info::<TestRoute>();
+    let info = use_route_info::<TestRoute>();
     let query = use_query_params();
     html! {
         <p id="probe">
             { format!(
-                "{}?lang={}",
-                route.map_or_else(|| "none".to_string(), |r| r.to_path()),
-                query.get("lang").unwrap_or("none")
+                "{}?lang={}#{}|{}|{}",
+                info.route.map_or_else(|| "none".to_string(), |r| r.to_path()),
+                query.get("lang").unwrap_or("none"),
+                info.fragment.unwrap_or_default(),
+                info.template.unwrap_or("none"),
+                info.is_not_found
             ) }
         </p>
     }
@@ -89,12 +102,56 @@ fn RouteInfoApp() -> Html {
 
 #[wasm_bindgen_test]
 async fn route_info_and_query_params_read_the_current_url() {
-    navigate("/docs?lang=rust");
+    navigate("/docs?lang=rust#intro");
     let root = fresh_root();
     yew::Renderer::<RouteInfoApp>::with_root(root).render();
     wait_for_render().await;
 
-    assert_eq!(probe_text(), "/docs?lang=rust");
+    assert_eq!(probe_text(), "/docs?lang=rust#intro|/docs|false");
+}
+
+#[derive(Clone, PartialEq, Debug, Routable)]
+enum UserRoute {
+    #[at("/users/:id")]
+    User { id: String },
+    #[not_found]
+    #[at("/404")]
+    NotFound
+}
+
+#[function_component]
+fn RouteParamProbe() -> Html {
+    let id = use_route_param::<UserRoute, u32>("id");
+    html! { <p id="probe">{ format!("{id:?}") }</p> }
+}
+
+#[function_component]
+fn RouteParamApp() -> Html {
+    html! {
+        <BrowserRouter>
+            <RouteParamProbe />
+        </BrowserRouter>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn route_param_parses_the_current_route() {
+    navigate("/users/42");
+    let root = fresh_root();
+    yew::Renderer::<RouteParamApp>::with_root(root).render();
+    wait_for_render().await;
+
+    assert_eq!(probe_text(), "Ok(42)");
+
+    navigate("/users/abc");
+    let root = fresh_root();
+    yew::Renderer::<RouteParamApp>::with_root(root).render();
+    wait_for_render().await;
+
+    assert!(
+        probe_text().starts_with("Err(InvalidRoute("),
+        "a value that does not parse is an InvalidRoute error"
+    );
 }
 
 #[function_component]
@@ -171,3 +228,177 @@ async fn breadcrumb_labels_come_from_the_injected_provider() {
 
     assert_eq!(probe_text(), "Home@/,Docs@/docs,API@/docs/api!");
 }
+
+/// Loads `/docs/api` asynchronously; `/docs` never resolves.
+struct AsyncLabels;
+
+impl AsyncBreadcrumbLabelProvider for AsyncLabels {
+    fn load_label(&self, path: &str) -> Option<LabelFuture> {
+        match path {
+            "/docs/api" => Some(Box::pin(async { "API reference".to_string() })),
+            "/docs" => Some(Box::pin(std::future::pending())),
+            _ => None
+        }
+    }
+}
+
+#[function_component]
+fn AsyncBreadcrumbApp() -> Html {
+    let context = use_memo((), |()| {
+        BreadcrumbLabelProviderContext::new(Rc::new(StaticLabels))
+            .with_async_provider(Rc::new(AsyncLabels))
+            .with_placeholder("…")
+    });
+    html! {
+        <BrowserRouter>
+            <ContextProvider<BreadcrumbLabelProviderContext> context={(*context).clone()}>
+                <BreadcrumbProbe />
+            </ContextProvider<BreadcrumbLabelProviderContext>>
+        </BrowserRouter>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn async_breadcrumb_labels_replace_placeholders_when_resolved() {
+    navigate("/docs/api");
+    let root = fresh_root();
+    yew::Renderer::<AsyncBreadcrumbApp>::with_root(root).render();
+    wait_for_render().await;
+    wait_for_render().await;
+
+    assert_eq!(
+        probe_text(),
+        "Home@/,…@/docs,API reference@/docs/api!",
+        "resolved labels replace the placeholder; pending ones keep it"
+    );
+}
+
+#[function_component]
+fn HiddenCrumbDepthApp() -> Html {
+    let context = use_memo((), |()| {
+        let labels = FnLabelProvider::new(|crumb: &BreadcrumbContext<TestRoute>| {
+            (crumb.depth == 1).then(|| "Second".to_string())
+        });
+        RouteLabelProviderContext::new(Rc::new(labels))
+    });
+    let config = BreadcrumbConfig::new().hide(PathPattern::parse("/docs").unwrap());
+    html! {
+        <BrowserRouter>
+            <ContextProvider<BreadcrumbConfig> context={config}>
+                <ContextProvider<RouteLabelProviderContext<TestRoute>> context={(*context).clone()}>
+                    <BreadcrumbProbe />
+                </ContextProvider<RouteLabelProviderContext<TestRoute>>>
+            </ContextProvider<BreadcrumbConfig>>
+        </BrowserRouter>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn label_provider_depth_counts_only_visible_crumbs() {
+    navigate("/docs/api");
+    let root = fresh_root();
+    yew::Renderer::<HiddenCrumbDepthApp>::with_root(root).render();
+    wait_for_render().await;
+
+    assert_eq!(
+        probe_text(),
+        "/@/,Second@/docs/api!",
+        "with /docs hidden, /docs/api is the second visible crumb"
+    );
+}
+
+#[function_component]
+fn RouteLabelApp() -> Html {
+    let context = use_memo((), |()| {
+        let labels = FnLabelProvider::new(|crumb: &BreadcrumbContext<TestRoute>| {
+            match crumb.route.as_ref()? {
+                TestRoute::DocsApi => Some(format!("{}#{}", crumb.segment, crumb.depth)),
+                _ => None
+            }
+        });
+        RouteLabelProviderContext::new(Rc::new(labels))
+    });
+    html! {
+        <BrowserRouter>
+            <ContextProvider<BreadcrumbLabelProviderContext>
+                context={BreadcrumbLabelProviderContext::new(Rc::new(StaticLabels))}
+            >
+                <ContextProvider<RouteLabelProviderContext<TestRoute>> context={(*context).clone()}>
+                    <BreadcrumbProbe />
+                </ContextProvider<RouteLabelProviderContext<TestRoute>>>
+            </ContextProvider<BreadcrumbLabelProviderContext>>
+        </BrowserRouter>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn route_label_provider_sees_route_and_segment_before_path_provider() {
+    navigate("/docs/api");
+    let root = fresh_root();
+    yew::Renderer::<RouteLabelApp>::with_root(root).render();
+    wait_for_render().await;
+
+    assert_eq!(
+        probe_text(),
+        "Home@/,Docs@/docs,api#2@/docs/api!",
+        "declined crumbs fall back to the path-based provider"
+    );
+}
+
+#[function_component]
+fn LocalizedBreadcrumbApp() -> Html {
+    let context = use_memo((), |()| {
+        let labels = MapLabelProvider::from_iter([("/", "Home"), ("/docs", "Docs")])
+            .with_locale("de", [("/", "Start")]);
+        RouteLabelProviderContext::<TestRoute>::new(Rc::new(labels)).with_locale("de")
+    });
+    html! {
+        <BrowserRouter>
+            <ContextProvider<RouteLabelProviderContext<TestRoute>> context={(*context).clone()}>
+                <BreadcrumbProbe />
+            </ContextProvider<RouteLabelProviderContext<TestRoute>>>
+        </BrowserRouter>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn map_label_provider_uses_the_context_locale() {
+    navigate("/docs/api");
+    let root = fresh_root();
+    yew::Renderer::<LocalizedBreadcrumbApp>::with_root(root).render();
+    wait_for_render().await;
+
+    assert_eq!(probe_text(), "Start@/,Docs@/docs,/docs/api@/docs/api!");
+}
+
+#[function_component]
+fn ConfiguredBreadcrumbApp() -> Html {
+    let config = BreadcrumbConfig::new()
+        .merge(PathPattern::parse("/docs/api").unwrap())
+        .rename(PathPattern::parse("/docs/:page").unwrap(), "Page {page}");
+    html! {
+        <BrowserRouter>
+            <ContextProvider<BreadcrumbLabelProviderContext>
+                context={BreadcrumbLabelProviderContext::new(Rc::new(StaticLabels))}
+            >
+                <ContextProvider<BreadcrumbConfig> context={config}>
+                    <BreadcrumbProbe />
+                </ContextProvider<BreadcrumbConfig>>
+            </ContextProvider<BreadcrumbLabelProviderContext>>
+        </BrowserRouter>
+    }
+}
+
+#[wasm_bindgen_test]
+async fn breadcrumb_config_merges_and_renames_crumbs() {
+    navigate("/docs/api");
+    let root = fresh_root();
+    yew::Renderer::<ConfiguredBreadcrumbApp>::with_root(root).render();
+    wait_for_render().await;
+
+    assert_eq!(
+        probe_text(),
+        "Home@/,Page api@/docs/api!",
+        "the merged parent disappears and the rename beats the provider"
+    );
+}
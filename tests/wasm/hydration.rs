@@ -0,0 +1,152 @@
+// SPDX-FileCopyrightText: RAprogramm <andrey.rozanov.vl@gmail.com>
+// SPDX-License-Identifier: MIT
+
+//! Browser tests for hydration: a page is rendered to a string under
+//! `StaticRouter`, written into a fresh root, and hydrated under
+//! `HydrationRouter`. The hydrated DOM must equal the server output with its
+//! hydration markers stripped, i.e. the first client render changed
+//! nothing. Needs `--features ssr,hydration`.
+
+#![cfg(all(feature = "ssr", feature = "hydration"))]
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Element, HtmlElement};
+use yew::{LocalServerRenderer, prelude::*};
+use yew_nav_link::{
+    Breadcrumbs, HydrationRouter, NavDropdown, NavDropdownDivider, NavDropdownItem, NavItem,
+    NavLink, NavList, NavTab, NavTabPanel, NavTabs, Pagination, StaticRouter
+};
+
+use super::common::{TestRoute, fresh_root, navigate, wait_for_render};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// The page under test, identical on both sides; only the router differs.
+#[function_component]
+fn Page() -> Html {
+    html! {
+        <>
+            <NavList aria_label="Main">
+                <NavItem>
+                    <NavLink<TestRoute> to={TestRoute::Home}>{ "Home" }</NavLink<TestRoute>>
+                </NavItem>
+                <NavItem>
+                    <NavLink<TestRoute> to={TestRoute::Docs} partial=true>
+                        { "Docs" }
+                    </NavLink<TestRoute>>
+                </NavItem>
+                <NavDropdown toggle_text="More" id="more-menu">
+                    <NavDropdownItem>
+                        <NavLink<TestRoute> to={TestRoute::About}>{ "About" }</NavLink<TestRoute>>
+                    </NavDropdownItem>
+                    <NavDropdownDivider />
+                    <NavDropdownItem disabled=true>{ "Admin" }</NavDropdownItem>
+                </NavDropdown>
+            </NavList>
+            <NavTabs id="tabs">
+                <NavTab active=true id="tab-1" panel_id="panel-1">{ "One" }</NavTab>
+                <NavTab active=false id="tab-2" panel_id="panel-2">{ "Two" }</NavTab>
+            </NavTabs>
+            <NavTabPanel id="panel-1" labelled_by="tab-1" hidden=false>{ "First" }</NavTabPanel>
+            <Breadcrumbs<TestRoute> />
+            <Pagination
+                current_page={2}
+                total_pages={4}
+                href_for={Callback::from(|page: u32| AttrValue::from(format!("/docs?page={page}")))}
+            />
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ServerAppProps {
+    url: AttrValue
+}
+
+#[function_component]
+fn ServerApp(props: &ServerAppProps) -> Html {
+    html! {
+        <StaticRouter url={props.url.clone()}>
+            <Page />
+        </StaticRouter>
+    }
+}
+
+#[function_component]
+fn ClientApp() -> Html {
+    html! {
+        <HydrationRouter>
+            <Page />
+        </HydrationRouter>
+    }
+}
+
+async fn server_render(url: &'static str, hydratable: bool) -> String {
+    LocalServerRenderer::<ServerApp>::with_props(ServerAppProps {
+        url: url.into()
+    })
+    .hydratable(hydratable)
+    .render()
+    .await
+}
+
+/// Renders `Page` on the "server" at `url`, hydrates it in a fresh root at
+/// the same browser URL, and asserts the hydrated DOM matches the server
+/// markup. Returns the root for follow-up interaction.
+async fn hydrate_at(url: &'static str) -> Element {
+    navigate(url);
+    let markup = server_render(url, true).await;
+    let expected = server_render(url, false).await;
+    assert_ne!(markup, expected, "hydratable output should carry markers");
+
+    let root = fresh_root();
+    root.set_inner_html(&markup);
+    yew::Renderer::<ClientApp>::with_root(root.clone()).hydrate();
+    wait_for_render().await;
+
+    assert_eq!(root.inner_html(), expected);
+    root
+}
+
+#[wasm_bindgen_test]
+async fn hydrated_dom_matches_server_markup() {
+    let root = hydrate_at("/docs/api").await;
+    let active = root
+        .query_selector("a.nav-link.active[aria-current='page']")
+        .unwrap()
+        .expect("active link after hydration");
+    assert_eq!(active.get_attribute("href").as_deref(), Some("/docs"));
+}
+
+#[wasm_bindgen_test]
+async fn hydrated_dom_matches_server_markup_on_the_root_route() {
+    hydrate_at("/").await;
+}
+
+#[wasm_bindgen_test]
+async fn dropdown_opens_only_after_hydration() {
+    let root = hydrate_at("/about").await;
+    let toggle: HtmlElement = root
+        .query_selector(".nav-dropdown-toggle")
+        .unwrap()
+        .unwrap()
+        .unchecked_into();
+    assert_eq!(
+        toggle.get_attribute("aria-expanded").as_deref(),
+        Some("false")
+    );
+
+    toggle.click();
+    wait_for_render().await;
+
+    assert_eq!(
+        toggle.get_attribute("aria-expanded").as_deref(),
+        Some("true")
+    );
+    assert!(
+        root.query_selector(".nav-dropdown-menu.open")
+            .unwrap()
+            .is_some()
+    );
+}
@@ -7,7 +7,7 @@
 
 use wasm_bindgen_test::*;
 use yew::prelude::*;
-use yew_nav_link::NavLink;
+use yew_nav_link::{Match, NavGroup, NavLink, use_link_state};
 use yew_router::prelude::*;
 
 use super::common::{TestRoute, fresh_root, navigate, wait_for_render};
@@ -44,6 +44,43 @@ fn PartialApp() -> Html {
     }
 }
 
+#[function_component]
+fn GroupApp() -> Html {
+    html! {
+        <BrowserRouter>
+            <NavGroup>
+                <NavLink<TestRoute> to={TestRoute::Home} partial=true>{ "Home" }</NavLink<TestRoute>>
+                <NavLink<TestRoute> to={TestRoute::Docs} partial=true>{ "Docs" }</NavLink<TestRoute>>
+                <NavLink<TestRoute> to={TestRoute::DocsApi} partial=true>{ "API" }</NavLink<TestRoute>>
+            </NavGroup>
+        </BrowserRouter>
+    }
+}
+
+#[function_component]
+fn CustomLink() -> Html {
+    let link = use_link_state(TestRoute::Docs, Match::Partial);
+    html! {
+        <a
+            class={link.class_with("card", "card-current")}
+            href={link.href.clone()}
+            onclick={link.onclick.clone()}
+            aria-current={link.aria_current}
+        >
+            { "Docs" }
+        </a>
+    }
+}
+
+#[function_component]
+fn CustomLinkApp() -> Html {
+    html! {
+        <BrowserRouter>
+            <CustomLink />
+        </BrowserRouter>
+    }
+}
+
 #[function_component]
 fn BasenameApp() -> Html {
     html! {
@@ -240,3 +277,65 @@ async fn modifier_clicks_fall_through_to_the_browser() {
     let path = web_sys::window().unwrap().location().pathname().unwrap();
     assert_eq!(path, "/", "ctrl/meta clicks must not be routed by NavLink");
 }
+
+#[wasm_bindgen_test]
+async fn nav_group_activates_only_the_most_specific_link() {
+    navigate("/docs/api");
+    let root = fresh_root();
+    yew::Renderer::<GroupApp>::with_root(root.clone()).render();
+    wait_for_render().await;
+    wait_for_render().await;
+
+    let links = root.get_elements_by_tag_name("a");
+    let class = |i| links.item(i).unwrap().get_attribute("class");
+    let current = |i| links.item(i).unwrap().get_attribute("aria-current");
+    assert_eq!(
+        class(0).as_deref(),
+        Some("nav-link"),
+        "Home is not on the trail"
+    );
+    assert_eq!(
+        class(1).as_deref(),
+        Some("nav-link active-trail"),
+        "Docs is an ancestor of the active link"
+    );
+    assert_eq!(class(2).as_deref(), Some("nav-link active"));
+    assert_eq!(current(1), None);
+    assert_eq!(current(2).as_deref(), Some("page"));
+
+    navigate("/");
+    root.remove();
+}
+
+#[wasm_bindgen_test]
+async fn link_state_drives_a_custom_link_like_nav_link() {
+    navigate("/");
+    let root = fresh_root();
+    yew::Renderer::<CustomLinkApp>::with_root(root.clone()).render();
+    wait_for_render().await;
+
+    let link = root.get_elements_by_tag_name("a").item(0).unwrap();
+    assert_eq!(link.get_attribute("class").as_deref(), Some("card"));
+    assert_eq!(link.get_attribute("href").as_deref(), Some("/docs"));
+    assert_eq!(link.get_attribute("aria-current"), None);
+
+    assert!(
+        click_with_modifiers(&link, false, false),
+        "a plain click is routed through the Navigator"
+    );
+    wait_for_render().await;
+
+    let link = root.get_elements_by_tag_name("a").item(0).unwrap();
+    assert_eq!(
+        web_sys::window().unwrap().location().pathname().unwrap(),
+        "/docs"
+    );
+    assert_eq!(
+        link.get_attribute("class").as_deref(),
+        Some("card card-current")
+    );
+    assert_eq!(link.get_attribute("aria-current").as_deref(), Some("page"));
+
+    navigate("/");
+    root.remove();
+}
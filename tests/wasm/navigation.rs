@@ -9,7 +9,9 @@ use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
 use web_sys::HtmlElement;
 use yew::prelude::*;
-use yew_nav_link::use_navigation;
+use yew_nav_link::{
+    NavigationHistoryProvider, use_history_stack, use_navigation, use_previous_route
+};
 use yew_router::prelude::*;
 
 use super::common::{TestRoute, document, fresh_root, navigate, wait_for_render};
@@ -148,3 +150,90 @@ async fn go_back_and_forward_walk_the_history_stack() {
     wait_for_render().await;
     assert_eq!(location_path(), "/", "go(-1) behaves like back");
 }
+
+#[function_component]
+fn HistoryProbe() -> Html {
+    let nav = use_navigation::<TestRoute>();
+    let previous = use_previous_route::<TestRoute>();
+    let stack = use_history_stack::<TestRoute>();
+    let push = nav
+        .push_callback(TestRoute::Docs)
+        .reform(|_: MouseEvent| ());
+    let replace = nav
+        .replace_callback(TestRoute::About)
+        .reform(|_: MouseEvent| ());
+    let back = nav.go_back_or(TestRoute::Home).reform(|_: MouseEvent| ());
+    let stack: Vec<String> = stack
+        .iter()
+        .map(|entry| format!("{:?}:{:?}", entry.route, entry.direction))
+        .collect();
+    html! {
+        <>
+            <p id="probe">
+                { format!("{previous:?}|{}|{}", nav.can_go_back(), stack.join(",")) }
+            </p>
+            <button id="push" onclick={push}>{ "push" }</button>
+            <button id="replace" onclick={replace}>{ "replace" }</button>
+            <button id="back" onclick={back}>{ "back" }</button>
+        </>
+    }
+}
+
+#[function_component]
+fn RecordedHistoryApp() -> Html {
+    html! {
+        <BrowserRouter>
+            <NavigationHistoryProvider<TestRoute>>
+                <HistoryProbe />
+            </NavigationHistoryProvider<TestRoute>>
+        </BrowserRouter>
+    }
+}
+
+fn probe_text() -> String {
+    document()
+        .get_element_by_id("probe")
+        .expect("probe should render")
+        .text_content()
+        .unwrap_or_default()
+}
+
+#[wasm_bindgen_test]
+async fn history_provider_records_push_replace_and_pop() {
+    navigate("/");
+    let root = fresh_root();
+    yew::Renderer::<RecordedHistoryApp>::with_root(root).render();
+    wait_for_render().await;
+    wait_for_render().await;
+    assert_eq!(probe_text(), "None|false|Home:Push");
+
+    click_by_id("push");
+    wait_for_render().await;
+    wait_for_render().await;
+    assert_eq!(probe_text(), "Some(Home)|true|Home:Push,Docs:Push");
+
+    click_by_id("replace");
+    wait_for_render().await;
+    wait_for_render().await;
+    assert_eq!(probe_text(), "Some(Home)|true|Home:Push,About:Replace");
+
+    click_by_id("back");
+    wait_for_render().await;
+    wait_for_render().await;
+    assert_eq!(location_path(), "/");
+    assert_eq!(
+        probe_text(),
+        "None|false|Home:Pop",
+        "back returns to the first entry, which is now a pop"
+    );
+
+    click_by_id("back");
+    wait_for_render().await;
+    wait_for_render().await;
+    assert_eq!(
+        location_path(),
+        "/",
+        "without an earlier in-app entry go_back_or pushes the fallback"
+    );
+    assert_eq!(probe_text(), "Some(Home)|true|Home:Pop,Home:Push");
+}
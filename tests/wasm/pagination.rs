@@ -4,7 +4,8 @@
 //! Browser tests for `Pagination` interaction: every control (prev/next,
 //! first/last jumps, numbered pages) emits the right page number through
 //! `on_page_change`, the active page stays focusable with
-//! `aria-current="page"`, and ellipsis gaps render as non-interactive text.
+//! `aria-current="page"` (or the configured token), and ellipsis gaps render
+//! as non-interactive text.
 
 use std::{cell::RefCell, rc::Rc};
 
@@ -12,7 +13,10 @@ use wasm_bindgen::JsCast;
 use wasm_bindgen_test::*;
 use web_sys::HtmlElement;
 use yew::prelude::*;
-use yew_nav_link::components::{Pagination, PaginationProps};
+use yew_nav_link::{
+    AriaCurrent,
+    components::{Pagination, PaginationProps}
+};
 
 use super::common::{document, fresh_root, wait_for_render};
 
@@ -31,7 +35,9 @@ fn render_pagination(clicks: &Rc<RefCell<Vec<u32>>>) {
         siblings:        1,
         show_first_last: true,
         show_prev_next:  true,
-        on_page_change:  Some(on_page_change)
+        on_page_change:  Some(on_page_change),
+        href_for:        None,
+        aria_current:    AriaCurrent::Page
     };
     yew::Renderer::<Pagination>::with_root_and_props(root, props).render();
 }
@@ -134,3 +140,27 @@ async fn ellipsis_renders_as_non_interactive_text() {
         .unwrap();
     assert!(buttons_inside.is_none(), "ellipsis must not be a button");
 }
+
+#[wasm_bindgen_test]
+async fn active_page_uses_the_configured_aria_current_token() {
+    let props = PaginationProps {
+        current_page: 2,
+        total_pages: 4,
+        aria_current: AriaCurrent::Step,
+        ..PaginationProps::default()
+    };
+    yew::Renderer::<Pagination>::with_root_and_props(fresh_root(), props).render();
+    wait_for_render().await;
+
+    let current = document().query_selector_all("[aria-current]").unwrap();
+    assert_eq!(current.length(), 1, "only the current page is marked");
+    let step = current.item(0).unwrap();
+    assert_eq!(step.text_content().as_deref(), Some("2"));
+    assert_eq!(
+        step.dyn_into::<HtmlElement>()
+            .unwrap()
+            .get_attribute("aria-current")
+            .as_deref(),
+        Some("step")
+    );
+}